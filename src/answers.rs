@@ -0,0 +1,144 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::try_read_file;
+use crate::workspace::Workspace;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// Known answers for a day, stored in `src/answers/NN.txt` of the selected year.
+/// Line `n` holds the answer to part `n`. An empty line marks the answer as unknown.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Answers {
+    parts: Vec<Option<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::Wrong { .. } => write!(f, "wrong"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for answer in &self.parts {
+            writeln!(f, "{}", answer.as_deref().unwrap_or_default())?;
+        }
+        Ok(())
+    }
+}
+
+impl Answers {
+    pub fn path(day: u8) -> PathBuf {
+        Workspace::current().file("answers", day, "txt")
+    }
+
+    /// Loads the stored answers for `day`. A missing answers file means all answers are unknown.
+    pub fn load(day: u8) -> Self {
+        try_read_file("answers", day)
+            .map(|contents| Self::parse(&contents))
+            .unwrap_or_default()
+    }
+
+    pub fn parse(contents: &str) -> Self {
+        let parts = contents
+            .lines()
+            .map(|line| match line.trim() {
+                "" => None,
+                answer => Some(answer.to_string()),
+            })
+            .collect();
+
+        Self { parts }
+    }
+
+    /// Stores the answer to `part`, e.g. after it was accepted by the website.
+    pub fn set(&mut self, part: u8, answer: &str) {
+        let index = (part as usize).saturating_sub(1);
+        if self.parts.len() <= index {
+            self.parts.resize(index + 1, None);
+        }
+        self.parts[index] = Some(answer.to_string());
+    }
+
+    pub fn save(&self, day: u8) -> io::Result<()> {
+        fs::write(Self::path(day), self.to_string())
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        let index = (part as usize).checked_sub(1)?;
+        self.parts.get(index)?.as_deref()
+    }
+
+    /// Compares a computed answer to the stored answer for `part`.
+    pub fn verify(&self, part: u8, answer: Option<&str>) -> Verdict {
+        match (self.get(part), answer) {
+            (None, _) => Verdict::Unknown,
+            (Some(expected), Some(answer)) if expected == answer => Verdict::Correct,
+            (Some(expected), _) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("1234\n\n");
+        assert_eq!(answers.get(1), Some("1234"));
+        assert_eq!(answers.get(2), None);
+        assert_eq!(answers.get(0), None);
+
+        let answers = Answers::parse("\nabc\n");
+        assert_eq!(answers.get(1), None);
+        assert_eq!(answers.get(2), Some("abc"));
+    }
+
+    #[test]
+    fn test_set() {
+        let mut answers = Answers::default();
+        answers.set(2, "abc");
+        assert_eq!(answers.to_string(), "\nabc\n");
+
+        answers.set(1, "1234");
+        assert_eq!(Answers::parse(&answers.to_string()), answers);
+        assert_eq!(answers.get(1), Some("1234"));
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse("7\n5");
+        assert_eq!(answers.verify(1, Some("7")), Verdict::Correct);
+        assert_eq!(
+            answers.verify(2, Some("6")),
+            Verdict::Wrong {
+                expected: "5".into()
+            }
+        );
+        assert_eq!(
+            answers.verify(2, None),
+            Verdict::Wrong {
+                expected: "5".into()
+            }
+        );
+        assert_eq!(answers.verify(3, Some("1")), Verdict::Unknown);
+    }
+}
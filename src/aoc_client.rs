@@ -0,0 +1,734 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process;
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Overrides the base URL, e.g. to run against a local mock server.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+pub const SESSION_ENV: &str = "ADVENT_OF_CODE_SESSION";
+const SESSION_FILE: &str = ".adventofcode.session";
+
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-rust";
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The default minimum time between two requests, to go easy on the website.
+pub const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(2);
+const LAST_REQUEST_FILE: &str = "advent-of-code-last-request";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    SessionNotFound,
+    /// The website rejected the session cookie.
+    SessionExpired,
+    /// The puzzle exists, but has not been unlocked yet.
+    NotUnlocked,
+    /// There is no puzzle for the requested day and year.
+    NotFound,
+    /// Any other error response, with its body.
+    Http {
+        status: u16,
+        body: String,
+    },
+    Transport(Box<ureq::Transport>),
+    Io(io::Error),
+    /// A downloaded file could not be written.
+    Write {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+impl AocClientError {
+    /// Classifies an error response by its status and body.
+    fn from_response(status: u16, body: String) -> Self {
+        if body.contains("Please log in")
+            || (status == 500 && body.contains("Internal Server Error"))
+        {
+            AocClientError::SessionExpired
+        } else if status == 404 && body.contains("before it unlocks") {
+            AocClientError::NotUnlocked
+        } else if status == 404 {
+            AocClientError::NotFound
+        } else {
+            AocClientError::Http { status, body }
+        }
+    }
+
+    /// What the user can do to fix the error, if anything.
+    pub fn remedy(&self) -> Option<String> {
+        match self {
+            AocClientError::SessionNotFound | AocClientError::SessionExpired => Some(format!(
+                "Copy the `session` cookie from the Advent of Code website into ~/{SESSION_FILE} or ${SESSION_ENV}. It expires after about a month."
+            )),
+            AocClientError::NotUnlocked => {
+                Some("Puzzles unlock at midnight UTC-5, try again once it is unlocked.".into())
+            }
+            AocClientError::NotFound => Some(
+                "Check the day and --year: puzzles exist for days 1 to 25 of every event since 2015.".into(),
+            ),
+            AocClientError::Transport(_) => Some(format!(
+                "Check your network connection, or ${BASE_URL_ENV} if you set it."
+            )),
+            AocClientError::Write { path, source }
+                if source.kind() == io::ErrorKind::PermissionDenied =>
+            {
+                let folder = path.parent().unwrap_or(path);
+                Some(format!("Make sure you are allowed to write to {folder:?}."))
+            }
+            _ => None,
+        }
+    }
+
+    /// Prints the error with `context` and its remedy, then exits.
+    pub fn exit(&self, context: &str) -> ! {
+        eprintln!("{context}: {self}");
+        if let Some(remedy) = self.remedy() {
+            eprintln!("{remedy}");
+        }
+        process::exit(1);
+    }
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocClientError::SessionNotFound => write!(
+                f,
+                "no session cookie found in ${SESSION_ENV} or ~/{SESSION_FILE}."
+            ),
+            AocClientError::SessionExpired => {
+                write!(f, "the session cookie is invalid or expired.")
+            }
+            AocClientError::NotUnlocked => write!(f, "the puzzle is not unlocked yet."),
+            AocClientError::NotFound => write!(f, "the puzzle does not exist."),
+            AocClientError::Http { status, .. } => {
+                write!(f, "the server responded with status {status}.")
+            }
+            AocClientError::Transport(e) => write!(f, "the request failed: {e}."),
+            AocClientError::Io(e) => write!(f, "could not read the response: {e}."),
+            AocClientError::Write { path, source } => {
+                write!(f, "could not write {path:?}: {source}.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for AocClientError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocClientError::Transport(e) => Some(e.as_ref()),
+            AocClientError::Io(e) | AocClientError::Write { source: e, .. } => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// A client for the Advent of Code website, authenticated with a session cookie.
+pub struct AocClient {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+    min_interval: Duration,
+    last_request: Mutex<Option<SystemTime>>,
+    /// Shares the time of the last request with other processes, e.g. consecutive downloads.
+    last_request_file: Option<PathBuf>,
+}
+
+impl AocClient {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(TIMEOUT)
+                .user_agent(USER_AGENT)
+                .build(),
+            min_interval: MIN_REQUEST_INTERVAL,
+            last_request: Mutex::new(None),
+            last_request_file: None,
+        }
+    }
+
+    pub fn with_min_interval(mut self, min_interval: Duration) -> Self {
+        self.min_interval = min_interval;
+        self
+    }
+
+    pub fn with_last_request_file(mut self, path: PathBuf) -> Self {
+        self.last_request_file = Some(path);
+        self
+    }
+
+    /// Creates a client with the session cookie from `$ADVENT_OF_CODE_SESSION` or
+    /// `~/.adventofcode.session` and the base URL from `$AOC_BASE_URL`, if set.
+    pub fn from_env() -> Result<Self, AocClientError> {
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.into());
+        let session = match env::var(SESSION_ENV) {
+            Ok(session) => session,
+            Err(_) => session_path()
+                .and_then(|path| fs::read_to_string(path).ok())
+                .ok_or(AocClientError::SessionNotFound)?,
+        };
+
+        if session.trim().is_empty() {
+            return Err(AocClientError::SessionNotFound);
+        }
+
+        Ok(Self::new(&base_url, &session)
+            .with_last_request_file(env::temp_dir().join(LAST_REQUEST_FILE)))
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    pub fn input(&self, year: u16, day: u8) -> Result<String, AocClientError> {
+        self.get(&format!("/{year}/day/{day}/input"))
+    }
+
+    /// The puzzle description, converted to markdown.
+    pub fn puzzle(&self, year: u16, day: u8) -> Result<String, AocClientError> {
+        self.get(&format!("/{year}/day/{day}"))
+            .map(|html| puzzle_to_markdown(&html))
+    }
+
+    /// Submits `answer` for `part` and returns how the website judged it.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<SubmitOutcome, AocClientError> {
+        let response = self
+            .request("POST", &format!("/{year}/day/{day}/answer"))
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        read_response(response).map(|html| SubmitOutcome::parse(&html))
+    }
+
+    fn get(&self, path: &str) -> Result<String, AocClientError> {
+        read_response(self.request("GET", path).call())
+    }
+
+    fn request(&self, method: &str, path: &str) -> ureq::Request {
+        self.throttle();
+        self.agent
+            .request(method, &format!("{}{path}", self.base_url))
+            .set("Cookie", &format!("session={}", self.session))
+    }
+
+    /// Waits until at least `min_interval` has passed since the last request.
+    fn throttle(&self) {
+        let mut last_request = self.last_request.lock().unwrap();
+        let shared = self
+            .last_request_file
+            .as_ref()
+            .and_then(|path| fs::read_to_string(path).ok())
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+
+        if let Some(last) = (*last_request).max(shared) {
+            if let Ok(wait) = (last + self.min_interval).duration_since(SystemTime::now()) {
+                thread::sleep(wait);
+            }
+        }
+
+        let now = SystemTime::now();
+        *last_request = Some(now);
+        if let (Some(path), Ok(elapsed)) = (&self.last_request_file, now.duration_since(UNIX_EPOCH))
+        {
+            // the limit still applies within this process if the file can't be written.
+            let _ = fs::write(path, elapsed.as_millis().to_string());
+        }
+    }
+}
+
+fn read_response(response: Result<ureq::Response, ureq::Error>) -> Result<String, AocClientError> {
+    match response {
+        Ok(response) => response.into_string().map_err(AocClientError::Io),
+        Err(ureq::Error::Status(status, response)) => Err(AocClientError::from_response(
+            status,
+            response.into_string().unwrap_or_default(),
+        )),
+        Err(ureq::Error::Transport(e)) => Err(AocClientError::Transport(Box::new(e))),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+impl Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+/// The website's verdict on a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SubmitOutcome {
+    Correct,
+    Incorrect(Option<Hint>),
+    /// The part was already solved, or its first part has not been solved yet.
+    AlreadySolved,
+    /// An answer was submitted too recently. Holds the remaining wait time, if stated.
+    RateLimited(Option<Duration>),
+    /// A response that could not be recognized, as markdown.
+    Unknown(String),
+}
+
+impl Display for SubmitOutcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitOutcome::Correct => write!(f, "correct"),
+            SubmitOutcome::Incorrect(Some(hint)) => write!(f, "incorrect, {hint}"),
+            SubmitOutcome::Incorrect(None) => write!(f, "incorrect"),
+            SubmitOutcome::AlreadySolved => write!(f, "already solved"),
+            SubmitOutcome::RateLimited(Some(wait)) => {
+                write!(f, "rate-limited, wait {}s", wait.as_secs())
+            }
+            SubmitOutcome::RateLimited(None) => write!(f, "rate-limited"),
+            SubmitOutcome::Unknown(message) => write!(f, "unknown response: {message}"),
+        }
+    }
+}
+
+impl SubmitOutcome {
+    /// Recognizes the `<article>` of an answer submission response.
+    pub fn parse(html: &str) -> Self {
+        let message = puzzle_to_markdown(html);
+
+        if message.contains("That's the right answer") {
+            SubmitOutcome::Correct
+        } else if message.contains("That's not the right answer") {
+            SubmitOutcome::Incorrect(if message.contains("your answer is too high") {
+                Some(Hint::TooHigh)
+            } else if message.contains("your answer is too low") {
+                Some(Hint::TooLow)
+            } else {
+                None
+            })
+        } else if message.contains("You gave an answer too recently") {
+            SubmitOutcome::RateLimited(parse_wait(&message))
+        } else if message.contains("Did you already complete it?") {
+            SubmitOutcome::AlreadySolved
+        } else {
+            SubmitOutcome::Unknown(message.trim().to_string())
+        }
+    }
+}
+
+/// Parses the wait time from "You have 1m 30s left to wait."
+fn parse_wait(message: &str) -> Option<Duration> {
+    let start = message.find("You have ")? + "You have ".len();
+    let end = message[start..].find(" left to wait")?;
+
+    message[start..start + end]
+        .split_whitespace()
+        .map(|amount| {
+            let (value, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+            match unit {
+                "h" => Some(value * 60 * 60),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+fn session_path() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(SESSION_FILE))
+}
+
+/// Converts the `<article>` elements of a puzzle page into markdown.
+/// Only covers the handful of elements used in puzzle descriptions.
+pub fn puzzle_to_markdown(html: &str) -> String {
+    // emphasized code is how answers are highlighted, keep the emphasis outside of the code span.
+    let html = html
+        .replace("<code><em>", "<em><code>")
+        .replace("</em></code>", "</code></em>");
+
+    let mut markdown = String::new();
+    let mut rest = html.as_str();
+
+    while let Some(start) = rest.find("<article") {
+        let Some(end) = rest[start..].find("</article>") else {
+            break;
+        };
+        let article = &rest[start..start + end];
+        let body = article.find('>').map_or("", |open| &article[open + 1..]);
+
+        markdown.push_str(&article_to_markdown(body));
+        end_block(&mut markdown);
+        rest = &rest[start + end..];
+    }
+
+    markdown.trim_end().to_string() + "\n"
+}
+
+fn article_to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut links: Vec<String> = vec![];
+    let mut in_pre = false;
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(open) = rest.find('<') else {
+            markdown.push_str(&decode_entities(rest));
+            break;
+        };
+
+        let text = &rest[..open];
+        // whitespace between block elements is only formatting of the html.
+        if in_pre || !(text.contains('\n') && text.trim().is_empty()) {
+            markdown.push_str(&decode_entities(text));
+        }
+
+        let Some(close) = rest[open..].find('>') else {
+            break;
+        };
+        let tag = &rest[open + 1..open + close];
+        rest = &rest[open + close + 1..];
+
+        let name = tag
+            .split_whitespace()
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        match name.as_str() {
+            "h2" => markdown.push_str("## "),
+            "/h2" | "/p" | "/ul" => end_block(&mut markdown),
+            "pre" => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            "/pre" => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```");
+                end_block(&mut markdown);
+            }
+            "code" | "/code" if !in_pre => markdown.push('`'),
+            "em" | "/em" if !in_pre => markdown.push('*'),
+            "li" => markdown.push_str("- "),
+            "/li" => markdown.push('\n'),
+            "a" => {
+                links.push(attribute(tag, "href").unwrap_or_default());
+                markdown.push('[');
+            }
+            "/a" => {
+                let href = links.pop().unwrap_or_default();
+                markdown.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    markdown
+}
+
+/// Ends a block with exactly one empty line.
+fn end_block(markdown: &mut String) {
+    markdown.truncate(markdown.trim_end_matches('\n').len());
+    markdown.push_str("\n\n");
+}
+
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let start = tag.find(&format!("{name}=\""))? + name.len() + 2;
+    let end = tag[start..].find('"')?;
+    Some(decode_entities(&tag[start..start + end]))
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let entity = rest.find(';').map(|end| (&rest[1..end], end));
+        let replacement = entity.and_then(|(name, end)| {
+            let c = match name {
+                "lt" => '<',
+                "gt" => '>',
+                "amp" => '&',
+                "quot" => '"',
+                "apos" => '\'',
+                _ => name
+                    .strip_prefix('#')
+                    .and_then(|code| match code.strip_prefix('x') {
+                        Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                        None => code.parse().ok(),
+                    })
+                    .and_then(char::from_u32)?,
+            };
+            Some((c, end))
+        });
+
+        match replacement {
+            Some((c, end)) => {
+                decoded.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    decoded.push_str(rest);
+    decoded
+}
+
+#[cfg(test)]
+pub(crate) mod mock_server {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    /// Serves one canned `(status, body)` response per incoming request and returns
+    /// the base URL together with a handle yielding the received requests.
+    pub fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = String::new();
+                let mut content_length = 0;
+
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(length) = line.to_lowercase().strip_prefix("content-length:") {
+                        content_length = length.trim().parse().unwrap();
+                    }
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                    request.push_str(&line);
+                }
+
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+
+            requests
+        });
+
+        (base_url, handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input() {
+        let (base_url, server) = mock_server::serve(vec![(200, "1\n2\n3\n")]);
+        let client = AocClient::new(&base_url, "abc\n");
+
+        assert_eq!(client.input(2021, 1).unwrap(), "1\n2\n3\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2021/day/1/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=abc\r\n"));
+    }
+
+    #[test]
+    fn test_http_error() {
+        let (base_url, server) = mock_server::serve(vec![
+            (
+                400,
+                "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+            ),
+            (
+                404,
+                "Please don't repeatedly request this endpoint before it unlocks!",
+            ),
+            (404, "404 Not Found"),
+            (503, "Service Unavailable"),
+        ]);
+        let client = AocClient::new(&base_url, "abc").with_min_interval(Duration::ZERO);
+
+        assert!(matches!(
+            client.input(2021, 1),
+            Err(AocClientError::SessionExpired)
+        ));
+        assert!(matches!(
+            client.input(2021, 1),
+            Err(AocClientError::NotUnlocked)
+        ));
+        assert!(matches!(
+            client.puzzle(2021, 26),
+            Err(AocClientError::NotFound)
+        ));
+        match client.puzzle(2021, 1) {
+            Err(AocClientError::Http { status, body }) => {
+                assert_eq!((status, body.as_str()), (503, "Service Unavailable"))
+            }
+            result => panic!("unexpected result: {result:?}"),
+        }
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_remedy() {
+        let error = AocClientError::Write {
+            path: "src/puzzles/01.md".into(),
+            source: io::Error::from(io::ErrorKind::PermissionDenied),
+        };
+        assert_eq!(
+            error.remedy(),
+            Some("Make sure you are allowed to write to \"src/puzzles\".".into())
+        );
+        assert!(std::error::Error::source(&error).is_some());
+        assert!(AocClientError::SessionExpired.remedy().is_some());
+        assert_eq!(
+            AocClientError::Http {
+                status: 503,
+                body: String::new()
+            }
+            .remedy(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_submit() {
+        let (base_url, server) = mock_server::serve(vec![(
+            200,
+            "<main><article><p>That's the right answer!  You are <em>one gold star</em> closer.</p></article></main>",
+        )]);
+        let client = AocClient::new(&base_url, "abc");
+
+        assert_eq!(
+            client.submit(2021, 1, 2, "1234").unwrap(),
+            SubmitOutcome::Correct
+        );
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2021/day/1/answer HTTP/1.1\r\n"));
+        assert!(requests[0].ends_with("\r\nlevel=2&answer=1234"));
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        let parse =
+            |message: &str| SubmitOutcome::parse(&format!("<article><p>{message}</p></article>"));
+
+        assert_eq!(
+            parse("That's not the right answer; your answer is too high.  If you're stuck, [...] Please wait one minute before trying again."),
+            SubmitOutcome::Incorrect(Some(Hint::TooHigh))
+        );
+        assert_eq!(
+            parse("That's not the right answer; your answer is too low."),
+            SubmitOutcome::Incorrect(Some(Hint::TooLow))
+        );
+        assert_eq!(
+            parse("That's not the right answer.  If you're stuck, [...]"),
+            SubmitOutcome::Incorrect(None)
+        );
+        assert_eq!(
+            parse("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2021/day/1\">[Return to Day 1]</a>"),
+            SubmitOutcome::AlreadySolved
+        );
+        assert_eq!(
+            parse("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 25s left to wait."),
+            SubmitOutcome::RateLimited(Some(Duration::from_secs(85)))
+        );
+        assert_eq!(
+            parse("Something else"),
+            SubmitOutcome::Unknown("Something else".into())
+        );
+    }
+
+    #[test]
+    fn test_min_interval() {
+        let (base_url, server) = mock_server::serve(vec![(200, "1"), (200, "2")]);
+        let client = AocClient::new(&base_url, "abc").with_min_interval(Duration::from_millis(200));
+
+        let start = std::time::Instant::now();
+        client.input(2021, 1).unwrap();
+        client.input(2021, 2).unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(200));
+
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_puzzle_to_markdown() {
+        let html = r#"<main><article class="day-desc"><h2>--- Day 1: Sonar Sweep ---</h2>
+<p>For example, see <a href="/2021/day/1/input" target="_blank">this</a> &amp; <em>that</em>:</p>
+<pre><code>199
+<em>200</em>
+</code></pre>
+<ul>
+<li>The answer is <code><em>7</em></code>.</li>
+</ul>
+</article>
+<p>Your puzzle answer was <code>1233</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>&lt;<code>x</code>&gt;</p></article></main>"#;
+
+        assert_eq!(
+            puzzle_to_markdown(html),
+            r#"## --- Day 1: Sonar Sweep ---
+
+For example, see [this](/2021/day/1/input) & *that*:
+
+```
+199
+200
+```
+
+- The answer is *`7`*.
+
+## --- Part Two ---
+
+<`x`>
+"#
+        );
+    }
+}
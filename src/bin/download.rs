@@ -2,20 +2,45 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
+use advent_of_code::aoc_client::{AocClient, AocClientError};
+use advent_of_code::download_cache::{CacheState, Checksums};
+use advent_of_code::runner::reject_unused;
+use advent_of_code::{calendar, workspace};
+use std::fs;
+use std::path::Path;
 use std::process;
 
 struct Args {
     day: u8,
     year: Option<u16>,
+    force: bool,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    Ok(Args {
-        day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
-    })
+    let parsed = Args {
+        force: args.contains(["-f", "--force"]),
+        year: args.opt_value_from_fn(["-y", "--year"], calendar::parse_year)?,
+        day: args.free_from_fn(calendar::parse_day)?,
+    };
+
+    reject_unused(args)?;
+    Ok(parsed)
+}
+
+fn write_file(path: &str, contents: &str) {
+    let written = Path::new(path)
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(path, contents));
+
+    if let Err(source) = written {
+        AocClientError::Write {
+            path: path.into(),
+            source,
+        }
+        .exit("Failed to save the download");
+    }
 }
 
 fn main() {
@@ -27,20 +52,71 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+    let year = args
+        .year
+        .or_else(workspace::configured_year)
+        .unwrap_or_else(calendar::latest_event_year);
+    if let Err(message) = calendar::check_unlocked(year, args.day) {
+        eprintln!("{message}");
         process::exit(1);
     }
 
-    match aoc_cli::download(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
-        }
+    let workspace = workspace::select(Some(year));
+    let input_path = workspace.input_path(args.day).display().to_string();
+    let puzzle_path = workspace.puzzle_path(args.day).display().to_string();
+
+    let mut checksums = match Checksums::load() {
+        Ok(checksums) => checksums,
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {e}");
+            eprintln!("Failed to read \"{}\": {e}", Checksums::path().display());
             process::exit(1);
         }
+    };
+    let input_state = checksums.state(args.day, Path::new(&input_path));
+    let fetch_input = args.force || input_state == CacheState::Missing;
+    let fetch_puzzle =
+        args.force || fs::metadata(&puzzle_path).map_or(true, |file| file.len() == 0);
+
+    if input_state == CacheState::Modified && !args.force {
+        eprintln!("⚠️ \"{input_path}\" was modified since it was downloaded. Run with --force to download it again.");
+    }
+
+    if !fetch_input && !fetch_puzzle {
+        println!("🎄 Input and puzzle of day {} are already downloaded. Run with --force to download them again.", args.day);
+        return;
+    }
+
+    let client = AocClient::from_env().unwrap_or_else(|e| e.exit("Failed to create client"));
+
+    println!("---");
+
+    if fetch_input {
+        let input = client.input(year, args.day).unwrap_or_else(|e| {
+            e.exit(&format!(
+                "Failed to download the input of day {} of {year}",
+                args.day
+            ))
+        });
+        write_file(&input_path, &input);
+        checksums.insert(args.day, &input);
+        if let Err(e) = checksums.save() {
+            eprintln!("Failed to write \"{}\": {e}", Checksums::path().display());
+        }
+        println!("🎄 Successfully wrote input to \"{input_path}\".");
+    } else {
+        println!("🎄 Input already present at \"{input_path}\".");
+    }
+
+    if fetch_puzzle {
+        let puzzle = client.puzzle(year, args.day).unwrap_or_else(|e| {
+            e.exit(&format!(
+                "Failed to download the puzzle of day {} of {year}",
+                args.day
+            ))
+        });
+        write_file(&puzzle_path, &puzzle);
+        println!("🎄 Successfully wrote puzzle to \"{puzzle_path}\".");
+    } else {
+        println!("🎄 Puzzle already present at \"{puzzle_path}\".");
     }
 }
@@ -2,20 +2,73 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::aoc_cli;
+use advent_of_code::aoc_client::{AocClient, AocClientError};
+use advent_of_code::config::config;
+use advent_of_code::runner::reject_unused;
+use advent_of_code::workspace::Workspace;
+use advent_of_code::{calendar, puzzle, workspace};
+use std::env;
+use std::fs;
 use std::process;
 
+/// The width puzzles are wrapped at unless `$COLUMNS` is set.
+const DEFAULT_WIDTH: usize = 80;
+
 struct Args {
     day: u8,
     year: Option<u16>,
+    part: Option<u8>,
+    refresh: bool,
+}
+
+fn parse_part(part: &str) -> Result<u8, String> {
+    match part {
+        "1" => Ok(1),
+        "2" => Ok(2),
+        _ => Err("expected part 1 or 2".into()),
+    }
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    Ok(Args {
-        day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
-    })
+    let parsed = Args {
+        part: args.opt_value_from_fn(["-p", "--part"], parse_part)?,
+        refresh: args.contains(["-r", "--refresh"]),
+        year: args.opt_value_from_fn(["-y", "--year"], calendar::parse_year)?,
+        day: args.free_from_fn(calendar::parse_day)?,
+    };
+
+    reject_unused(args)?;
+    Ok(parsed)
+}
+
+/// Reads the downloaded puzzle, or downloads and saves it if it is missing or `refresh` is set.
+fn read_puzzle(year: u16, day: u8, refresh: bool) -> String {
+    let path = Workspace::current().puzzle_path(day);
+    match fs::read_to_string(&path) {
+        Ok(markdown) if !refresh && !markdown.trim().is_empty() => return markdown,
+        _ => {}
+    }
+
+    if let Err(message) = calendar::check_unlocked(year, day) {
+        eprintln!("{message}");
+        process::exit(1);
+    }
+
+    let client = AocClient::from_env().unwrap_or_else(|e| e.exit("Failed to create client"));
+    let markdown = client
+        .puzzle(year, day)
+        .unwrap_or_else(|e| e.exit(&format!("Failed to read day {day} of {year}")));
+
+    let saved = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .and_then(|_| fs::write(&path, &markdown));
+    if let Err(source) = saved {
+        AocClientError::Write { path, source }.exit("Failed to save the puzzle");
+    }
+
+    markdown
 }
 
 fn main() {
@@ -27,20 +80,28 @@ fn main() {
         }
     };
 
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
-    }
+    let year = args
+        .year
+        .or_else(workspace::configured_year)
+        .unwrap_or_else(calendar::latest_event_year);
+    workspace::select(Some(year));
 
-    match aoc_cli::read(args.day, args.year) {
-        Ok(cmd_output) => {
-            if !cmd_output.status.success() {
-                process::exit(1);
-            }
-        }
-        Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {e}");
+    let markdown = read_puzzle(year, args.day, args.refresh);
+    let markdown = match args.part {
+        Some(part) => puzzle::part(&markdown, part).unwrap_or_else(|| {
+            eprintln!(
+                "Part {part} of day {} is not available yet. Solve part one and run `cargo read {} --part {part} --refresh` to get it.",
+                args.day, args.day
+            );
             process::exit(1);
-        }
-    }
+        }),
+        None => &markdown,
+    };
+
+    let width = env::var("COLUMNS")
+        .ok()
+        .and_then(|columns| columns.parse().ok())
+        .unwrap_or(DEFAULT_WIDTH);
+
+    print!("{}", puzzle::render(markdown, width, config().colors));
 }
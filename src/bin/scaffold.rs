@@ -2,113 +2,793 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::calendar;
+use advent_of_code::config::config;
+use advent_of_code::puzzle;
+use advent_of_code::runner::reject_unused;
+use advent_of_code::workspace::Workspace;
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+const MODULE_TEMPLATE: &str = r###"use advent_of_code::solution::{Answer, Solution, SolveError};
+
+pub struct DayDAY_PADDED;
+
+impl Solution for DayDAY_PADDED {
+    const DAY: u8 = DAY_NUMBER;
+
+    fn part_one(_input: &str) -> Result<Answer, SolveError> {
+        let answer: Option<ANSWER_TYPE> = None;
+        answer.map(Answer::from).ok_or(SolveError::NotImplemented)
+    }
+
+    fn part_two(_input: &str) -> Result<Answer, SolveError> {
+        let answer: Option<ANSWER_TYPE> = None;
+        answer.map(Answer::from).ok_or(SolveError::NotImplemented)
+    }
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+advent_of_code::main!(DayDAY_PADDED);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::aoc_tests!(DayDAY_PADDED, part_one = PART_ONE_EXPECTED, part_two = PART_TWO_EXPECTED);
 }
+"###;
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+const GRID_TEMPLATE: &str = r###"use advent_of_code::parser::{self, ParseError};
+use advent_of_code::solution::{Answer, Solution, SolveError};
+
+pub struct DayDAY_PADDED;
+
+fn parse_input(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    parser::grid(input, |c| c.to_digit(10))
+}
+
+impl Solution for DayDAY_PADDED {
+    const DAY: u8 = DAY_NUMBER;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let _grid = parse_input(input)?;
+        let answer: Option<ANSWER_TYPE> = None;
+        answer.map(Answer::from).ok_or(SolveError::NotImplemented)
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let _grid = parse_input(input)?;
+        let answer: Option<ANSWER_TYPE> = None;
+        answer.map(Answer::from).ok_or(SolveError::NotImplemented)
+    }
 }
 
+advent_of_code::main!(DayDAY_PADDED);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+    advent_of_code::aoc_tests!(DayDAY_PADDED, part_one = PART_ONE_EXPECTED, part_two = PART_TWO_EXPECTED);
+}
+"###;
+
+const PARSE_LINES_TEMPLATE: &str = r###"use advent_of_code::parser::{self, ParseError};
+use advent_of_code::solution::{Answer, Solution, SolveError};
+
+pub struct DayDAY_PADDED;
+
+fn parse_input(input: &str) -> Result<Vec<u32>, ParseError> {
+    parser::lines(input).map(|line| line.parse()).collect()
+}
+
+impl Solution for DayDAY_PADDED {
+    const DAY: u8 = DAY_NUMBER;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let _lines = parse_input(input)?;
+        let answer: Option<ANSWER_TYPE> = None;
+        answer.map(Answer::from).ok_or(SolveError::NotImplemented)
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let _lines = parse_input(input)?;
+        let answer: Option<ANSWER_TYPE> = None;
+        answer.map(Answer::from).ok_or(SolveError::NotImplemented)
     }
 }
+
+advent_of_code::main!(DayDAY_PADDED);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    advent_of_code::aoc_tests!(DayDAY_PADDED, part_one = PART_ONE_EXPECTED, part_two = PART_TWO_EXPECTED);
+}
+"###;
+
+/// A single part and no tests, e.g. for day 25.
+const BLANK_TEMPLATE: &str = r###"use advent_of_code::solution::{Answer, Solution, SolveError};
+
+pub struct DayDAY_PADDED;
+
+impl Solution for DayDAY_PADDED {
+    const DAY: u8 = DAY_NUMBER;
+
+    fn part_one(_input: &str) -> Result<Answer, SolveError> {
+        Err(SolveError::NotImplemented)
+    }
+}
+
+advent_of_code::main!(DayDAY_PADDED, part_one);
 "###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
+/// The module of a year other than `days::YEAR`, holding its own `DAYS`.
+const YEAR_MODULE_TEMPLATE: &str = r###"/*
+ * Solutions of YEAR_NUMBER, registered in `YEARS` in `src/days/mod.rs`.
+ * `cargo scaffold --year YEAR_NUMBER` adds a `pub mod` line and a `day!` entry to `DAYS` for a new day.
+ */
+
+use super::Day;
+use crate::solution::Solution;
+
+pub const DAYS: &[Day] = &[
+];
+"###;
+
+/// Solutions registered with `cargo all` live here.
+const DAYS_DIR: &str = "src/days";
+
+const TEMPLATES: [(&str, &str); 4] = [
+    ("default", MODULE_TEMPLATE),
+    ("grid", GRID_TEMPLATE),
+    ("parse-lines", PARSE_LINES_TEMPLATE),
+    ("blank", BLANK_TEMPLATE),
+];
+
+struct Args {
+    day: u8,
+    year: Option<u16>,
+    template: String,
+    answer: String,
+    example: Option<usize>,
+    expected: [Option<String>; 2],
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+    let parsed = Args {
+        year: args.opt_value_from_fn(["-y", "--year"], calendar::parse_year)?,
+        template: args
+            .opt_value_from_str(["-t", "--template"])?
+            .unwrap_or_else(|| config().template.clone()),
+        answer: args
+            .opt_value_from_str("--answer")?
+            .unwrap_or_else(|| config().answer.clone()),
+        example: args.opt_value_from_str("--example")?,
+        expected: [
+            args.opt_value_from_str("--part-one")?,
+            args.opt_value_from_str("--part-two")?,
+        ],
+        day: args.free_from_fn(calendar::parse_day)?,
+    };
+
+    reject_unused(args)?;
+    Ok(parsed)
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+/// Loads the template `name` from the templates folder, falling back to the built-in ones.
+fn load_template(name: &str) -> Result<String, String> {
+    let path = Path::new(&config().templates).join(format!("{name}.rs"));
+
+    match fs::read_to_string(&path) {
+        Ok(template) => Ok(template),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => TEMPLATES
+            .iter()
+            .find(|(builtin, _)| *builtin == name)
+            .map(|(_, template)| template.to_string())
+            .ok_or_else(|| {
+                let builtins = TEMPLATES.map(|(builtin, _)| builtin).join(", ");
+                format!(
+                    "Unknown template `{name}`, expected one of {builtins} or a file in \"{}\".",
+                    config().templates
+                )
+            }),
+        Err(e) => Err(format!("Failed to read template {path:?}: {e}")),
+    }
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// The example input and expected answers found in the downloaded puzzle description.
+/// `--example` picks another code block and `--part-one`/`--part-two` set the answers.
+fn extract_example(args: &Args, workspace: Workspace) -> (Option<String>, [Option<String>; 2]) {
+    let Ok(markdown) = fs::read_to_string(workspace.puzzle_path(args.day)) else {
+        return (None, args.expected.clone());
+    };
+
+    let example = match args.example {
+        Some(block) => match puzzle::code_blocks(&markdown)
+            .into_iter()
+            .nth(block.wrapping_sub(1))
+        {
+            Some(example) => Some(example),
+            None => {
+                eprintln!("The puzzle description has no code block {block}.");
+                process::exit(1);
+            }
+        },
+        None => puzzle::example(&markdown),
+    };
+
+    let [part_one, part_two] = puzzle::example_answers(&markdown);
+    let expected = [
+        args.expected[0].clone().or(part_one),
+        args.expected[1].clone().or(part_two),
+    ];
+
+    (example, expected)
 }
 
-fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
-            process::exit(1);
-        }
+/// The expected answer of a part in `aoc_tests!`. Numbers that do not fit an `i32` get a
+/// suffix and negative ones parentheses, so they are a single token.
+fn expected_result(answer: &Option<String>) -> String {
+    let Some(answer) = answer else {
+        return "not_implemented".into();
+    };
+    let number = match answer.parse::<i128>() {
+        Ok(number) if i32::try_from(number).is_ok() => number.to_string(),
+        Ok(number) => format!("{number}_i128"),
+        Err(_) => return format!("{answer:?}"),
     };
 
-    let day_padded = format!("{day:02}");
+    match number.starts_with('-') {
+        true => format!("({number})"),
+        false => number,
+    }
+}
+
+/// Fills in the placeholders of a template. Year-scoped solutions also declare their year and
+/// read their examples from the year's folder.
+fn module_contents(
+    template: &str,
+    day: u8,
+    workspace: Workspace,
+    answer: &str,
+    expected: &[Option<String>; 2],
+) -> String {
+    let template = match workspace.scoped_year() {
+        Some(year) => template
+            .replace(
+                "    const DAY: u8 = DAY_NUMBER;\n",
+                &format!("    const DAY: u8 = DAY_NUMBER;\n    const YEAR: Option<u16> = Some({year});\n"),
+            )
+            .replace(
+                "advent_of_code::read_file(\"examples\", ",
+                &format!("advent_of_code::read_year_file({year}, \"examples\", "),
+            ),
+        None => template.to_string(),
+    };
+    let year = workspace.year.unwrap_or_else(calendar::latest_event_year);
 
-    let input_path = format!("src/inputs/{day_padded}.txt");
-    let example_path = format!("src/examples/{day_padded}.txt");
-    let module_path = format!("src/bin/{day_padded}.rs");
+    template
+        .replace("DAY_PADDED", &format!("{day:02}"))
+        .replace("DAY_NUMBER", &day.to_string())
+        .replace("YEAR_NUMBER", &year.to_string())
+        .replace("ANSWER_TYPE", answer)
+        .replace("PART_ONE_EXPECTED", &expected_result(&expected[0]))
+        .replace("PART_TWO_EXPECTED", &expected_result(&expected[1]))
+}
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+/// The folder of the solution modules of a workspace, e.g. `src/days` or `src/days/y2022`.
+fn days_dir(workspace: Workspace) -> PathBuf {
+    match workspace.scoped_year() {
+        Some(year) => Path::new(DAYS_DIR).join(format!("y{year}")),
+        None => PathBuf::from(DAYS_DIR),
+    }
+}
+
+/// The path of the solution modules of a workspace, e.g. `days` or `days::y2022`.
+fn days_module(workspace: Workspace) -> String {
+    match workspace.scoped_year() {
+        Some(year) => format!("days::y{year}"),
+        None => "days".into(),
+    }
+}
+
+/// A solution kept in `src/days` so `cargo all` can run it, with a binary that calls into it.
+struct LibrarySolution {
+    module: String,
+    binary: String,
+    /// The `DAYS` entry of the solution, e.g. `day!(day07::Day07)`.
+    entry: String,
+    parts: usize,
+}
+
+impl LibrarySolution {
+    /// Splits a filled-in template into a library module of `days_module` and the binary
+    /// running its `main!`.
+    fn new(contents: &str, day: u8, days_module: &str) -> Self {
+        let main = contents
+            .lines()
+            .find(|line| line.starts_with("advent_of_code::main!("))
+            .map(|line| line.to_string())
+            .unwrap_or_else(|| format!("advent_of_code::main!(Day{day:02});"));
+        let arguments = main
+            .trim_start_matches("advent_of_code::main!(")
+            .trim_end_matches(';')
+            .trim_end_matches(')');
+        let solution = arguments.split(',').next().unwrap_or_default().trim();
+
+        let module = contents
+            .replace(&format!("{main}\n\n"), "")
+            .replace(&format!("{main}\n"), "")
+            .replace("advent_of_code::", "crate::");
+
+        Self {
+            module: module.trim_end().to_string() + "\n",
+            binary: format!(
+                "use advent_of_code::{days_module}::day{day:02}::{solution};\n\n{main}\n"
+            ),
+            entry: format!("day!(day{day:02}::{arguments})"),
+            parts: if arguments.contains("part_one") { 1 } else { 2 },
+        }
+    }
+}
+
+/// Writes a new module file, keeping an existing one so scaffolding a day twice is harmless.
+fn create_module(path: &Path, contents: &str) {
+    let file = OpenOptions::new().write(true).create_new(true).open(path);
+
+    match file.and_then(|mut file| file.write_all(contents.as_bytes())) {
+        Ok(_) => {
+            println!("Created module file \"{}\"", path.display());
+        }
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            println!("Kept existing module file \"{}\"", path.display());
+        }
         Err(e) => {
             eprintln!("Failed to create module file: {e}");
             process::exit(1);
         }
+    }
+}
+
+/// Adds `day` to the registry at `path`, e.g. `src/days/mod.rs`, so `cargo all` runs it.
+fn register_day(path: &Path, day: u8, entry: &str) {
+    update_registry(path, &format!("day {day}"), |registry| {
+        with_registered_day(registry, day, entry)
+            .map_err(|e| format!("{e}. Add `pub mod day{day:02};` and `{entry},` by hand."))
+    });
+}
+
+/// Adds the module of `year` to `src/days/mod.rs` so `cargo all --year` runs its days.
+fn register_year(year: u16) {
+    let dir = Path::new(DAYS_DIR).join(format!("y{year}"));
+    if let Err(e) = fs::create_dir_all(&dir) {
+        eprintln!("Failed to create folder {dir:?}: {e}");
+        process::exit(1);
+    }
+    create_module(
+        &dir.join("mod.rs"),
+        &YEAR_MODULE_TEMPLATE.replace("YEAR_NUMBER", &year.to_string()),
+    );
+
+    update_registry(
+        &Path::new(DAYS_DIR).join("mod.rs"),
+        &format!("year {year}"),
+        |registry| {
+            with_registered_year(registry, year).map_err(|e| {
+                format!(
+                    "{e}. Add `pub mod y{year};` and `({year}, y{year}::DAYS)` to `YEARS` by hand."
+                )
+            })
+        },
+    );
+}
+
+/// Rewrites the registry at `path` with `update`, which returns `None` if there is nothing to do.
+fn update_registry(
+    path: &Path,
+    name: &str,
+    update: impl FnOnce(&str) -> Result<Option<String>, String>,
+) {
+    let registry = match fs::read_to_string(path) {
+        Ok(registry) => registry,
+        Err(e) => {
+            eprintln!("Failed to read {path:?}: {e}");
+            process::exit(1);
+        }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
+    match update(&registry) {
+        Ok(Some(registry)) => match fs::write(path, registry) {
+            Ok(_) => println!("Registered {name} in \"{}\"", path.display()),
+            Err(e) => {
+                eprintln!("Failed to write {path:?}: {e}");
+                process::exit(1);
+            }
+        },
+        Ok(None) => {}
+        Err(e) => {
+            eprintln!("Failed to register {name} in {path:?}: {e}");
+            process::exit(1);
         }
+    }
+}
+
+/// The registry with the `pub mod` line and the `DAYS` entry of `day` inserted in order, or
+/// `None` if the day is registered already.
+fn with_registered_day(registry: &str, day: u8, entry: &str) -> Result<Option<String>, String> {
+    let module = format!("pub mod day{day:02};");
+    if registry.lines().any(|line| line == module) {
+        return Ok(None);
+    }
+
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+
+    let days_start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const DAYS"))
+        .ok_or("`DAYS` not found")?;
+    let days_end = lines[days_start..]
+        .iter()
+        .position(|line| line == "];")
+        .map(|end| days_start + end)
+        .ok_or("end of `DAYS` not found")?;
+    let position = sorted_position(&lines[days_start + 1..days_end], "    day!(day", day)
+        .map_or(days_end, |position| days_start + 1 + position);
+    lines.insert(position, format!("    {entry},"));
+
+    let position = match sorted_position(&lines, "pub mod day", day) {
+        Some(position) => position,
+        None => {
+            let position = lines
+                .iter()
+                .position(|line| line.starts_with("use "))
+                .ok_or("no `pub mod` or `use` lines found")?;
+            lines.insert(position, String::new());
+            position
+        }
+    };
+    lines.insert(position, module);
+
+    Ok(Some(lines.join("\n") + "\n"))
+}
+
+/// The registry with the `pub mod` line of `year` and its `YEARS` entry added, or `None` if
+/// the year is registered already.
+fn with_registered_year(registry: &str, year: u16) -> Result<Option<String>, String> {
+    let module = format!("pub mod y{year};");
+    if registry.lines().any(|line| line == module) {
+        return Ok(None);
+    }
+
+    let mut lines: Vec<String> = registry.lines().map(String::from).collect();
+
+    let years_start = lines
+        .iter()
+        .position(|line| line.starts_with("pub const YEARS"))
+        .ok_or("`YEARS` not found")?;
+    let years_end = lines[years_start..]
+        .iter()
+        .position(|line| line.ends_with("];"))
+        .map(|end| years_start + end)
+        .ok_or("end of `YEARS` not found")?;
+    let entry = format!("({year}, y{year}::DAYS)");
+    if years_start == years_end {
+        let line = &mut lines[years_end];
+        line.insert_str(line.len() - 2, &format!(", {entry}"));
+    } else {
+        lines.insert(years_end, format!("    {entry},"));
+    }
+
+    // year modules are declared after the `day!` macro, so they can use it.
+    let position = match lines.iter().rposition(|line| line.starts_with("pub mod y")) {
+        Some(last) => last + 1,
+        None => {
+            let docs = lines[..years_start]
+                .iter()
+                .rev()
+                .take_while(|line| line.starts_with("///"))
+                .count();
+            lines.insert(years_start - docs, String::new());
+            years_start - docs
+        }
+    };
+    lines.insert(position, module);
+
+    Ok(Some(lines.join("\n") + "\n"))
+}
+
+/// Where the line of `day` goes among the lines starting with `prefix` and a padded day,
+/// or `None` if there are no such lines.
+fn sorted_position(lines: &[String], prefix: &str, day: u8) -> Option<usize> {
+    let days: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let registered = line.strip_prefix(prefix)?.get(..2)?.parse().ok()?;
+            Some((index, registered))
+        })
+        .collect();
+
+    days.iter()
+        .find(|(_, registered)| *registered > day)
+        .map(|(index, _)| *index)
+        .or_else(|| days.last().map(|(index, _)| index + 1))
+}
+
+/// Creates an answers file with an empty line per part, so answers can be filled in later.
+fn create_answers(workspace: Workspace, day: u8, parts: usize) {
+    let path = workspace.file("answers", day, "txt");
+    if path.exists() {
+        return;
+    }
+
+    let created = fs::create_dir_all(workspace.dir("answers"))
+        .and_then(|_| fs::write(&path, "\n".repeat(parts)));
+    match created {
+        Ok(_) => println!("Created empty answers file \"{}\"", path.display()),
         Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
+            eprintln!("Failed to create answers file: {e}");
             process::exit(1);
         }
     }
+}
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+/// Creates an empty file, keeping an existing one like `create_module`.
+fn create_empty_file(path: &str, name: &str) {
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(_) => println!("Created empty {name} file \"{path}\""),
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+            println!("Kept existing {name} file \"{path}\"")
         }
         Err(e) => {
-            eprintln!("Failed to create input file: {e}");
+            eprintln!("Failed to create {name} file: {e}");
             process::exit(1);
         }
     }
+}
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::MissingArgument) => {
+            eprintln!("Need to specify a day between 1 and 25. example: `cargo scaffold 7`");
+            process::exit(1);
+        }
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    // the year of `DAYS` keeps the regular folders and any other year gets its own folder,
+    // see `advent_of_code::workspace`.
+    let workspace = match args.year {
+        Some(year) => Workspace::new(Some(year)),
+        None => Workspace::current(),
+    };
+
+    for folder in ["inputs", "examples"] {
+        if let Err(e) = fs::create_dir_all(workspace.dir(folder)) {
+            eprintln!("Failed to create folder {:?}: {e}", workspace.dir(folder));
+            process::exit(1);
         }
+    }
+
+    let template = match load_template(&args.template) {
+        Ok(template) => template,
         Err(e) => {
-            eprintln!("Failed to create example file: {e}");
+            eprintln!("{e}");
             process::exit(1);
         }
+    };
+
+    let day = args.day;
+    let input_path = workspace.input_path(day).display().to_string();
+    let example_path = workspace.file("examples", day, "txt").display().to_string();
+
+    let (example, expected) = extract_example(&args, workspace);
+    let contents = module_contents(&template, day, workspace, &args.answer, &expected);
+
+    if let Some(year) = workspace.scoped_year() {
+        register_year(year);
+    }
+
+    let solution = LibrarySolution::new(&contents, day, &days_module(workspace));
+    let days_dir = days_dir(workspace);
+    create_module(&days_dir.join(format!("day{day:02}.rs")), &solution.module);
+    create_module(&workspace.bin_path(day), &solution.binary);
+    register_day(&days_dir.join("mod.rs"), day, &solution.entry);
+    create_answers(workspace, day, solution.parts);
+
+    create_empty_file(&input_path, "input");
+
+    let has_example = fs::metadata(&example_path).is_ok_and(|metadata| metadata.len() > 0);
+    match example.filter(|_| !has_example) {
+        Some(example) => match fs::write(&example_path, example) {
+            Ok(_) => {
+                println!(
+                    "Created example file \"{}\" from the puzzle description",
+                    &example_path
+                );
+            }
+            Err(e) => {
+                eprintln!("Failed to write example file: {e}");
+                process::exit(1);
+            }
+        },
+        None => create_empty_file(&example_path, "example"),
+    }
+
+    match expected {
+        [None, None] => {}
+        [part_one, part_two] => println!(
+            "Expecting {} for part one and {} for part two of the example",
+            part_one.as_deref().unwrap_or("nothing"),
+            part_two.as_deref().unwrap_or("nothing")
+        ),
+    }
+
+    if !workspace.puzzle_path(day).exists() {
+        println!(
+            "Download the puzzle description to \"{}\" before scaffolding to fill in the example.",
+            workspace.puzzle_path(day).display()
+        );
     }
 
     println!("---");
     println!(
         "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
+        workspace.bin_name(day)
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REGISTRY: &str = "pub mod day01;\npub mod day03;\n\nuse crate::solution::Solution;\n\npub const DAYS: &[Day] = &[\n    day!(day01::Day01),\n    day!(day03::Day03),\n];\n";
+
+    #[test]
+    fn test_register_day() {
+        let registry = with_registered_day(REGISTRY, 2, "day!(day02::Day02)")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            registry,
+            "pub mod day01;\npub mod day02;\npub mod day03;\n\nuse crate::solution::Solution;\n\npub const DAYS: &[Day] = &[\n    day!(day01::Day01),\n    day!(day02::Day02),\n    day!(day03::Day03),\n];\n"
+        );
+        assert_eq!(
+            with_registered_day(&registry, 2, "day!(day02::Day02)"),
+            Ok(None)
+        );
+
+        let registry = with_registered_day(REGISTRY, 25, "day!(day25::Day25, part_one)")
+            .unwrap()
+            .unwrap();
+        assert!(registry.contains("pub mod day03;\npub mod day25;\n"));
+        assert!(registry.contains("    day!(day25::Day25, part_one),\n];"));
+    }
+
+    #[test]
+    fn test_library_solution() {
+        let solution = LibrarySolution::new(
+            "use advent_of_code::solution::Solution;\n\nadvent_of_code::main!(Day25, part_one);\n\nfn test() {\n    advent_of_code::read_file(\"examples\", 25);\n}\n",
+            25,
+            "days",
+        );
+        assert_eq!(
+            solution.module,
+            "use crate::solution::Solution;\n\nfn test() {\n    crate::read_file(\"examples\", 25);\n}\n"
+        );
+        assert_eq!(
+            solution.binary,
+            "use advent_of_code::days::day25::Day25;\n\nadvent_of_code::main!(Day25, part_one);\n"
+        );
+        assert_eq!(solution.entry, "day!(day25::Day25, part_one)");
+        assert_eq!(solution.parts, 1);
+
+        let solution = LibrarySolution::new("advent_of_code::main!(Day03);\n", 3, "days::y2022");
+        assert_eq!(
+            solution.binary,
+            "use advent_of_code::days::y2022::day03::Day03;\n\nadvent_of_code::main!(Day03);\n"
+        );
+        assert_eq!(solution.entry, "day!(day03::Day03)");
+    }
+
+    #[test]
+    fn test_templates_use_their_bindings() {
+        let indent = |line: &str| line.len() - line.trim_start().len();
+
+        for (name, template) in TEMPLATES {
+            let module = module_contents(template, 3, Workspace::new(None), "u32", &[None, None]);
+            let lines: Vec<&str> = module.lines().collect();
+
+            for (i, line) in lines.iter().enumerate() {
+                // parameters are used in the body of their function, bindings in their block.
+                let (bindings, scope_indent): (Vec<&str>, usize) = match line.split_once("fn ") {
+                    Some((_, signature)) => (
+                        signature
+                            .split_once('(')
+                            .map_or("", |(_, params)| params)
+                            .split(',')
+                            .filter_map(|param| param.split_once(':'))
+                            .map(|(name, _)| name.trim())
+                            .collect(),
+                        indent(line) + 1,
+                    ),
+                    None => (
+                        line.trim()
+                            .strip_prefix("let ")
+                            .and_then(|rest| rest.split([':', ' ', '=']).next())
+                            .into_iter()
+                            .collect(),
+                        indent(line),
+                    ),
+                };
+
+                let scope: Vec<&str> = lines[i + 1..]
+                    .iter()
+                    .take_while(|body| body.is_empty() || indent(body) >= scope_indent)
+                    .flat_map(|body| body.split(|c: char| !c.is_alphanumeric() && c != '_'))
+                    .collect();
+
+                for binding in bindings.iter().filter(|binding| !binding.starts_with('_')) {
+                    assert!(
+                        scope.contains(binding),
+                        "`{binding}` is unused in the {name} template"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_expected_result() {
+        let expected = |answer: &str| expected_result(&Some(answer.to_string()));
+        assert_eq!(expected("17"), "17");
+        assert_eq!(expected("-3"), "(-3)");
+        assert_eq!(expected("2188189693529"), "2188189693529_i128");
+        assert_eq!(expected("ABC"), "\"ABC\"");
+        assert_eq!(expected_result(&None), "not_implemented");
+    }
+
+    #[test]
+    fn test_register_year() {
+        let registry = "macro_rules! day {}\n\npub const YEAR: u16 = 2021;\n\n/// All years.\npub const YEARS: &[(u16, &[Day])] = &[(YEAR, DAYS)];\n";
+        let registry = with_registered_year(registry, 2022).unwrap().unwrap();
+        assert_eq!(
+            registry,
+            "macro_rules! day {}\n\npub const YEAR: u16 = 2021;\n\npub mod y2022;\n\n/// All years.\npub const YEARS: &[(u16, &[Day])] = &[(YEAR, DAYS), (2022, y2022::DAYS)];\n"
+        );
+        assert_eq!(with_registered_year(&registry, 2022), Ok(None));
+
+        let registry = with_registered_year(
+            &registry
+                .replace("= &[(YEAR", "= &[\n    (YEAR")
+                .replace(", (2022", ",\n    (2022")
+                .replace("::DAYS)];", "::DAYS),\n];"),
+            2020,
+        )
+        .unwrap()
+        .unwrap();
+        assert!(registry.contains("pub mod y2022;\npub mod y2020;\n"));
+        assert!(registry.contains("    (2022, y2022::DAYS),\n    (2020, y2020::DAYS),\n];"));
+    }
+
+    #[test]
+    fn test_register_first_day() {
+        let registry = YEAR_MODULE_TEMPLATE.replace("YEAR_NUMBER", "2022");
+        let registry = with_registered_day(&registry, 3, "day!(day03::Day03)")
+            .unwrap()
+            .unwrap();
+        assert!(registry.contains(" */\n\npub mod day03;\n\nuse super::Day;\n"));
+        assert!(registry.contains("&[\n    day!(day03::Day03),\n];"));
+    }
+}
@@ -0,0 +1,154 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use advent_of_code::answers::Answers;
+use advent_of_code::aoc_client::{AocClient, SubmitOutcome};
+use advent_of_code::days;
+use advent_of_code::runner::reject_unused;
+use advent_of_code::submissions::Submissions;
+use advent_of_code::try_read_file;
+use advent_of_code::{calendar, workspace};
+use std::process;
+
+struct Args {
+    day: u8,
+    part: u8,
+    year: Option<u16>,
+}
+
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        year: args.opt_value_from_fn(["-y", "--year"], calendar::parse_year)?,
+        day: args.free_from_fn(calendar::parse_day)?,
+        part: args.free_from_str()?,
+    };
+
+    reject_unused(args)?;
+    Ok(parsed)
+}
+
+fn exit_with(message: String) -> ! {
+    eprintln!("{message}");
+    process::exit(1);
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => exit_with(format!("Failed to process arguments: {e}")),
+    };
+
+    let year = args
+        .year
+        .or_else(workspace::configured_year)
+        .unwrap_or_else(calendar::latest_event_year);
+    if let Err(message) = calendar::check_unlocked(year, args.day) {
+        exit_with(message);
+    }
+    workspace::select(Some(year));
+
+    let Some(days) = days::registered(Some(year)) else {
+        exit_with(format!(
+            "No solutions of {year} are registered in src/days/mod.rs, select another year with --year."
+        ));
+    };
+    let Some(day) = days.iter().find(|day| day.day == args.day) else {
+        exit_with(format!(
+            "Day {} of {year} is not registered in src/days/mod.rs.",
+            args.day
+        ));
+    };
+    let solver = match args.part {
+        1 => day.part_one,
+        2 => match day.part_two {
+            Some(solver) => solver,
+            None => exit_with(format!("Day {} has no part 2.", args.day)),
+        },
+        _ => exit_with(format!("Invalid part {}, expected 1 or 2.", args.part)),
+    };
+    if day.is_manual(args.part) {
+        exit_with(format!(
+            "Day {} part {} is read by hand, run `cargo solve {}` and submit the answer on the website.",
+            args.day,
+            args.part,
+            workspace::Workspace::current().bin_name(args.day)
+        ));
+    }
+
+    let input = match try_read_file("inputs", args.day) {
+        Ok(input) => input,
+        Err(e) => exit_with(format!("Failed to read input for day {:02}: {e}", args.day)),
+    };
+    let answer = match solver(&input) {
+        Ok(answer) => answer.to_string(),
+        Err(e) => exit_with(format!(
+            "Day {} part {} has no answer: {e}",
+            args.day, args.part
+        )),
+    };
+
+    let mut submissions = match Submissions::load(args.day) {
+        Ok(submissions) => submissions,
+        Err(e) => exit_with(format!("Failed to read submission history: {e}")),
+    };
+    if let Some(reason) = submissions.refusal(args.part, &answer) {
+        exit_with(format!("Not submitting: {reason}."));
+    }
+
+    let client = AocClient::from_env().unwrap_or_else(|e| e.exit("Failed to create client"));
+
+    println!(
+        "Submitting {answer} for day {}, part {}...",
+        args.day, args.part
+    );
+    let outcome = match client.submit(year, args.day, args.part, &answer) {
+        Ok(outcome) => outcome,
+        Err(e) => e.exit("Failed to submit"),
+    };
+
+    submissions.record(args.part, &answer, &outcome);
+    if let Err(e) = submissions.save(args.day) {
+        eprintln!(
+            "Failed to write \"{}\": {e}",
+            Submissions::path(args.day).display()
+        );
+    }
+
+    match outcome {
+        SubmitOutcome::Correct => {
+            println!("🎄 That's the right answer!");
+
+            let mut answers = Answers::load(args.day);
+            if answers.get(args.part).is_none() {
+                answers.set(args.part, &answer);
+                match answers.save(args.day) {
+                    Ok(()) => println!(
+                        "🎄 Saved the answer to \"{}\".",
+                        Answers::path(args.day).display()
+                    ),
+                    Err(e) => eprintln!("Failed to save the answer: {e}"),
+                }
+            }
+        }
+        SubmitOutcome::Incorrect(Some(hint)) => {
+            exit_with(format!("That's not the right answer, {answer} is {hint}."))
+        }
+        SubmitOutcome::Incorrect(None) => exit_with("That's not the right answer.".into()),
+        SubmitOutcome::AlreadySolved => exit_with(format!(
+            "Part {} is already solved or not unlocked yet.",
+            args.part
+        )),
+        SubmitOutcome::RateLimited(Some(wait)) => exit_with(format!(
+            "An answer was submitted too recently, try again in {}s.",
+            wait.as_secs()
+        )),
+        SubmitOutcome::RateLimited(None) => {
+            exit_with("An answer was submitted too recently, try again later.".into())
+        }
+        SubmitOutcome::Unknown(message) => {
+            exit_with(format!("Could not recognize the response:\n{message}"))
+        }
+    }
+}
@@ -0,0 +1,161 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// The year of the first Advent of Code event.
+pub const FIRST_YEAR: u16 = 2015;
+pub const LAST_DAY: u8 = 25;
+
+/// Puzzles unlock at midnight UTC-5, i.e. at 05:00 UTC.
+const UNLOCK_OFFSET_SECONDS: i64 = 5 * 60 * 60;
+
+/// Parses a day between 1 and 25, for use with `pico_args`.
+pub fn parse_day(day: &str) -> Result<u8, String> {
+    match day.parse::<u8>() {
+        Ok(day @ 1..=LAST_DAY) => Ok(day),
+        _ => Err(format!("expected a day between 1 and {LAST_DAY}")),
+    }
+}
+
+/// Parses a year between 2015 and the current year, for use with `pico_args`.
+pub fn parse_year(year: &str) -> Result<u16, String> {
+    let current = current_date().0;
+    match year.parse::<u16>() {
+        Ok(year) if (FIRST_YEAR..=current).contains(&year) => Ok(year),
+        _ => Err(format!(
+            "expected a year between {FIRST_YEAR} and {current}"
+        )),
+    }
+}
+
+/// The year of the most recent event.
+pub fn latest_event_year() -> u16 {
+    match current_date() {
+        (year, 12) => year,
+        (year, _) => year - 1,
+    }
+}
+
+/// The time at which the puzzle of `day` in `year` unlocks.
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    let seconds = days_from_civil(year as i64, 12, day as u32) * 86400 + UNLOCK_OFFSET_SECONDS;
+    UNIX_EPOCH + Duration::from_secs(seconds as u64)
+}
+
+/// Fails with the remaining time if the puzzle of `day` in `year` is not unlocked yet.
+pub fn check_unlocked(year: u16, day: u8) -> Result<(), String> {
+    match unlock_time(year, day).duration_since(SystemTime::now()) {
+        Ok(remaining) => Err(format!(
+            "Day {day} of {year} unlocks in {}.",
+            format_remaining(remaining)
+        )),
+        Err(_) => Ok(()),
+    }
+}
+
+/// Formats a duration as e.g. `3d 4h 5m 6s`, leaving out leading zero units.
+fn format_remaining(remaining: Duration) -> String {
+    let seconds = remaining.as_secs();
+    let units = [
+        (seconds / 86400, "d"),
+        (seconds / 3600 % 24, "h"),
+        (seconds / 60 % 60, "m"),
+        (seconds % 60, "s"),
+    ];
+
+    let formatted = units
+        .iter()
+        .skip_while(|(value, _)| *value == 0)
+        .map(|(value, unit)| format!("{value}{unit}"))
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    if formatted.is_empty() {
+        "0s".into()
+    } else {
+        formatted
+    }
+}
+
+/// The current (year, month) in UTC-5, the timezone puzzles unlock in.
+fn current_date() -> (u16, u32) {
+    let seconds = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() as i64)
+        .unwrap_or_default();
+    let (year, month, _) = civil_from_days((seconds - UNLOCK_OFFSET_SECONDS).div_euclid(86400));
+
+    (year as u16, month)
+}
+
+/// Converts days since 1970-01-01 into a (year, month, day) date.
+/// see: http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    (year, month, day)
+}
+
+/// Converts a (year, month, day) date into days since 1970-01-01.
+/// see: http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let mp = (month as i64 + 9) % 12;
+    let day_of_year = (153 * mp + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_civil_dates() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(18962), (2021, 12, 1));
+        assert_eq!(civil_from_days(-1), (1969, 12, 31));
+        assert_eq!(days_from_civil(2021, 12, 1), 18962);
+        assert_eq!(days_from_civil(2024, 2, 29), 19782);
+        assert_eq!(civil_from_days(19782), (2024, 2, 29));
+    }
+
+    #[test]
+    fn test_unlock_time() {
+        assert_eq!(
+            unlock_time(2021, 1),
+            UNIX_EPOCH + Duration::from_secs(1638334800)
+        );
+        assert_eq!(check_unlocked(2021, 25), Ok(()));
+        assert_eq!(
+            format_remaining(Duration::from_secs(2 * 86400 + 65)),
+            "2d 0h 1m 5s"
+        );
+        assert_eq!(format_remaining(Duration::from_millis(500)), "0s");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse_day("7"), Ok(7));
+        assert!(parse_day("0").is_err());
+        assert!(parse_day("31").is_err());
+        assert!(parse_day("x").is_err());
+        assert_eq!(parse_year("2015"), Ok(2015));
+        assert!(parse_year("2014").is_err());
+        assert!(parse_year("9999").is_err());
+    }
+}
@@ -0,0 +1,283 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::calendar;
+use crate::parser::{self, ParseError, Span};
+use crate::runner::OutputFormat;
+use std::fs;
+use std::io;
+use std::process;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+/// The project configuration, read from the working directory.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Project-wide defaults, read from `aoc.toml`. Command line flags take precedence.
+///
+/// The file supports a subset of TOML: `key = value` pairs with string, integer and
+/// boolean values, grouped into `[sections]`. All keys are optional.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Config {
+    /// `year`: the year used when no `--year` is given.
+    pub year: Option<u16>,
+    /// `[paths] inputs`: the folder of inputs within `src`.
+    pub inputs: String,
+    /// `[paths] examples`
+    pub examples: String,
+    /// `[paths] puzzles`
+    pub puzzles: String,
+    /// `[paths] answers`
+    pub answers: String,
+    /// `[run] timeout`: the time limit per part in seconds for `cargo all`, 0 disables it.
+    pub timeout: Option<Duration>,
+    /// `[run] jobs`: how many days `cargo all` runs at once.
+    pub jobs: usize,
+    /// `[run] format`: `text`, `json` or `csv`.
+    pub format: OutputFormat,
+    /// `[run] baseline`: the benchmark baseline file used when no `--baseline` is given.
+    pub baseline: String,
+    /// `[run] threshold`: the slowdown in percent flagged as a regression.
+    pub threshold: f64,
+    /// `[output] colors`: whether text output is styled with ANSI escape codes.
+    pub colors: bool,
+    /// `[scaffold] template`: the template used when no `--template` is given.
+    pub template: String,
+    /// `[scaffold] templates`: the folder of custom templates.
+    pub templates: String,
+    /// `[scaffold] answer`: the answer type used when no `--answer` is given.
+    pub answer: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            year: None,
+            inputs: "inputs".into(),
+            examples: "examples".into(),
+            puzzles: "puzzles".into(),
+            answers: "answers".into(),
+            timeout: Some(Duration::from_secs(60)),
+            jobs: 1,
+            format: OutputFormat::Text,
+            baseline: "bench_baseline.csv".into(),
+            threshold: 10.0,
+            colors: true,
+            template: "default".into(),
+            templates: "templates".into(),
+            answer: "u32".into(),
+        }
+    }
+}
+
+/// The configuration of this project. Exits if `aoc.toml` is invalid.
+pub fn config() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+
+    CONFIG.get_or_init(|| match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Failed to read {CONFIG_FILE}: {e}");
+            process::exit(1);
+        }
+    })
+}
+
+/// Styles text with an `ANSI_*` code, unless colors are disabled in `aoc.toml`.
+pub fn ansi(code: &'static str) -> &'static str {
+    if config().colors {
+        code
+    } else {
+        ""
+    }
+}
+
+impl Config {
+    /// Loads `aoc.toml`. A missing file yields the default configuration.
+    pub fn load() -> Result<Self, String> {
+        match fs::read_to_string(CONFIG_FILE) {
+            Ok(contents) => Self::parse(&contents).map_err(|e| e.to_string()),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub fn parse(contents: &str) -> Result<Self, ParseError> {
+        let mut config = Self::default();
+        let mut section = "";
+
+        for line in parser::lines(contents) {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Ok(name) = line.strip_prefix("[") {
+                section = name
+                    .text
+                    .strip_suffix(']')
+                    .ok_or_else(|| line.error("expected ']'"))?;
+                continue;
+            }
+
+            let (key, value) = line.split_once("=")?;
+            let (key, value) = (key.trim(), value.trim());
+
+            match (section, key.as_str()) {
+                ("", "year") => {
+                    let year = calendar::parse_year(value.as_str())
+                        .map_err(|e| value.error(format!("invalid `year`, {e}")))?;
+                    config.year = Some(year);
+                }
+                ("paths", "inputs") => config.inputs = string(value)?,
+                ("paths", "examples") => config.examples = string(value)?,
+                ("paths", "puzzles") => config.puzzles = string(value)?,
+                ("paths", "answers") => config.answers = string(value)?,
+                ("run", "timeout") => {
+                    config.timeout = match value.parse()? {
+                        0 => None,
+                        seconds => Some(Duration::from_secs(seconds)),
+                    }
+                }
+                ("run", "jobs") => config.jobs = value.parse()?,
+                ("run", "format") => {
+                    config.format = string(value)?.parse().map_err(|e: String| value.error(e))?
+                }
+                ("run", "baseline") => config.baseline = string(value)?,
+                ("run", "threshold") => config.threshold = value.parse()?,
+                ("output", "colors") => config.colors = value.parse()?,
+                ("scaffold", "template") => config.template = string(value)?,
+                ("scaffold", "templates") => config.templates = string(value)?,
+                ("scaffold", "answer") => config.answer = string(value)?,
+                _ => return Err(key.error(format!("unknown key in [{section}]"))),
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// The configured name of one of the `src` folders, e.g. `inputs`.
+    pub fn folder<'a>(&'a self, folder: &'a str) -> &'a str {
+        match folder {
+            "inputs" => &self.inputs,
+            "examples" => &self.examples,
+            "puzzles" => &self.puzzles,
+            "answers" => &self.answers,
+            _ => folder,
+        }
+    }
+}
+
+/// Removes a `#` comment, unless the `#` is part of a string.
+fn strip_comment(line: Span) -> Span {
+    let mut in_string = false;
+
+    for (span, c) in line.chars() {
+        match c {
+            '"' => in_string = !in_string,
+            '#' if !in_string => {
+                let end = span.text.as_ptr() as usize - line.text.as_ptr() as usize;
+                return Span {
+                    text: &line.text[..end],
+                    ..line
+                };
+            }
+            _ => {}
+        }
+    }
+
+    line
+}
+
+fn string(value: Span) -> Result<String, ParseError> {
+    value
+        .text
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .map(String::from)
+        .ok_or_else(|| value.error("expected a quoted string"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(
+            r#"
+# the year of this repository
+year = 2021
+
+[paths]
+inputs = "inputs-#1" # not a comment inside a string
+
+[run]
+timeout = 0
+jobs = 4
+format = "json"
+baseline = "bench/baseline.csv"
+threshold = 2.5
+
+[output]
+colors = false
+
+[scaffold]
+template = "grid"
+answer = "usize"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                year: Some(2021),
+                inputs: "inputs-#1".into(),
+                timeout: None,
+                jobs: 4,
+                format: OutputFormat::Json,
+                baseline: "bench/baseline.csv".into(),
+                threshold: 2.5,
+                colors: false,
+                template: "grid".into(),
+                answer: "usize".into(),
+                ..Config::default()
+            }
+        );
+        assert_eq!(config.folder("inputs"), "inputs-#1");
+        assert_eq!(config.folder("examples"), "examples");
+        assert_eq!(config.folder("inputs-alt"), "inputs-alt");
+        assert_eq!(Config::parse(""), Ok(Config::default()));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Config::parse("[run]\njobs = many").unwrap_err().to_string(),
+            "line 2, column 8: invalid digit found in string, found \"many\""
+        );
+        assert_eq!(
+            Config::parse("[paths]\ninput = \"x\"")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 1: unknown key in [paths], found \"input\""
+        );
+        assert_eq!(
+            Config::parse("[paths]\ninputs = x")
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 10: expected a quoted string, found \"x\""
+        );
+
+        let error = Config::parse("# the year\nyear = 2012").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.text.as_str()),
+            (2, 8, "2012")
+        );
+        assert!(error
+            .message
+            .starts_with("invalid `year`, expected a year between 2015 and "));
+    }
+}
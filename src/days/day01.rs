@@ -0,0 +1,41 @@
+use crate::parser::{self, ParseError};
+use crate::solution::{Answer, Solution, SolveError};
+
+fn parse_input(input: &str) -> Result<Vec<usize>, ParseError> {
+    parser::lines(input).map(|line| line.parse()).collect()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let measurements = parse_input(input)?;
+
+        Ok(Answer::from(
+            measurements
+                .windows(2)
+                .filter(|window| window[0] < window[1])
+                .count() as u32,
+        ))
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let measurements = parse_input(input)?;
+
+        Ok(Answer::from(
+            measurements
+                .windows(4)
+                .filter(|window| window[0] < window[3])
+                .count() as u32,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_tests!(Day01, part_one = 7, part_two = 5);
+}
@@ -0,0 +1,63 @@
+use self::Command::{Down, Forward, Up};
+use crate::parser::{self, ParseError};
+use crate::solution::{Answer, Solution, SolveError};
+
+enum Command {
+    Forward(usize),
+    Down(usize),
+    Up(usize),
+}
+
+fn parse_input(input: &str) -> Result<Vec<Command>, ParseError> {
+    parser::lines(input)
+        .map(|line| {
+            let (command, amount) = line.split_once(" ")?;
+            let amount = amount.parse()?;
+
+            match command.as_str() {
+                "forward" => Ok(Forward(amount)),
+                "down" => Ok(Down(amount)),
+                "up" => Ok(Up(amount)),
+                _ => Err(command.error("expected \"forward\", \"down\" or \"up\"")),
+            }
+        })
+        .collect()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let result = parse_input(input)?
+            .iter()
+            .fold((0, 0), |(depth, position), command| match command {
+                Forward(amount) => (depth, position + amount),
+                Down(amount) => (depth + amount, position),
+                Up(amount) => (depth - amount, position),
+            });
+
+        Ok(Answer::from((result.0 * result.1) as u32))
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let result =
+            parse_input(input)?
+                .iter()
+                .fold((0, 0, 0), |(depth, position, aim), command| match command {
+                    Forward(amount) => (depth + amount * aim, position + amount, aim),
+                    Down(amount) => (depth, position, aim + amount),
+                    Up(amount) => (depth, position, aim - amount),
+                });
+
+        Ok(Answer::from((result.0 * result.1) as u32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_tests!(Day02, part_one = 150, part_two = 900);
+}
@@ -0,0 +1,118 @@
+use crate::parser::{ParseError, Span};
+use crate::solution::{Answer, Solution, SolveError};
+use std::collections::HashSet;
+
+#[derive(Debug)]
+struct BingoBoard {
+    board: Vec<Vec<usize>>,
+}
+
+impl BingoBoard {
+    fn won(&self, numbers: &HashSet<usize>) -> bool {
+        // First check if any of the rows causes the board to be winning.
+        if self
+            .board
+            .iter()
+            .any(|row| row.iter().all(|number| numbers.contains(number)))
+        {
+            return true;
+        }
+
+        // Then check if any of the columns causes the board to be winning.
+        (0..self.board[0].len()).any(|colum_index| {
+            self.board
+                .iter()
+                .all(|row| numbers.contains(&row[colum_index]))
+        })
+    }
+
+    fn unused_numbers(&self, numbers: &HashSet<usize>) -> Vec<usize> {
+        self.board
+            .iter()
+            .flat_map(|row| {
+                row.iter()
+                    .filter(|number| !numbers.contains(number))
+                    .cloned()
+                    .collect::<Vec<usize>>()
+            })
+            .collect()
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<usize>, Vec<BingoBoard>), ParseError> {
+    let (numbers, boards) = Span::new(input).split_once("\n\n")?;
+
+    let numbers = numbers
+        .split(",")
+        .map(|number| number.parse())
+        .collect::<Result<_, _>>()?;
+
+    let boards = boards
+        .blocks()
+        .map(|block| {
+            let board = block
+                .lines()
+                .map(|line| {
+                    line.split_whitespace()
+                        .map(|number| number.parse())
+                        .collect()
+                })
+                .collect::<Result<_, _>>()?;
+
+            Ok(BingoBoard { board })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok((numbers, boards))
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let (numbers, boards) = parse_input(input)?;
+        let mut played_numbers: HashSet<usize> = HashSet::new();
+
+        for number in numbers {
+            played_numbers.insert(number);
+
+            if let Some(winning_board) = boards.iter().find(|board| board.won(&played_numbers)) {
+                let sum_of_unused_numbers: usize =
+                    winning_board.unused_numbers(&played_numbers).iter().sum();
+
+                return Ok(Answer::from(sum_of_unused_numbers * number));
+            }
+        }
+
+        Err(SolveError::unsolvable("no board wins"))
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let (numbers, mut boards) = parse_input(input)?;
+        let mut played_numbers: HashSet<usize> = HashSet::new();
+
+        for number in numbers {
+            played_numbers.insert(number);
+
+            if boards.len() == 1 && boards[0].won(&played_numbers) {
+                let sum_of_unused_numbers: usize =
+                    boards[0].unused_numbers(&played_numbers).iter().sum();
+
+                return Ok(Answer::from(sum_of_unused_numbers * number));
+            }
+
+            boards.retain(|board| !board.won(&played_numbers));
+        }
+
+        Err(SolveError::unsolvable("no single board wins last"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_tests!(Day04, part_one = 4512, part_two = 1924);
+}
@@ -0,0 +1,88 @@
+use crate::parser::{ParseError, Span};
+use crate::solution::{Answer, Solution, SolveError};
+use std::collections::HashMap;
+
+struct School {
+    fishes_per_day: HashMap<usize, usize>,
+}
+
+impl School {
+    pub fn new(fishes: Vec<usize>) -> Self {
+        Self {
+            fishes_per_day: fishes.into_iter().fold(HashMap::new(), |mut map, fish| {
+                *map.entry(fish).or_default() += 1;
+                map
+            }),
+        }
+    }
+
+    pub fn update(&self) -> Self {
+        let mut updated_amount_of_fish: HashMap<usize, usize> = HashMap::new();
+
+        for (day, amount) in &self.fishes_per_day {
+            match day {
+                0 => {
+                    *updated_amount_of_fish.entry(6).or_default() += amount;
+                    *updated_amount_of_fish.entry(8).or_default() += amount;
+                }
+                _ => {
+                    *updated_amount_of_fish.entry(day - 1).or_default() += amount;
+                }
+            }
+        }
+
+        School {
+            fishes_per_day: updated_amount_of_fish,
+        }
+    }
+
+    pub fn number_of_fish(&self) -> usize {
+        self.fishes_per_day.values().sum()
+    }
+}
+
+fn parse_input(input: &str) -> Result<School, ParseError> {
+    let fishes = Span::new(input)
+        .trim()
+        .split(",")
+        .map(|element| element.parse())
+        .collect::<Result<_, _>>()?;
+
+    Ok(School::new(fishes))
+}
+
+fn determine_number_of_fishes_after(fishes: School, days: usize) -> usize {
+    let mut current_school = fishes;
+
+    for _ in 0..days {
+        current_school = current_school.update();
+    }
+
+    current_school.number_of_fish()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        Ok(Answer::from(
+            determine_number_of_fishes_after(parse_input(input)?, 80) as u32,
+        ))
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        Ok(Answer::from(determine_number_of_fishes_after(
+            parse_input(input)?,
+            256,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_tests!(Day06, part_one = 5934, part_two = 26984457539_u64);
+}
@@ -0,0 +1,76 @@
+use crate::parser::{ParseError, Span};
+use crate::solution::{Answer, Solution, SolveError};
+
+fn parse_input(input: &str) -> Result<Vec<isize>, ParseError> {
+    Span::new(input)
+        .trim()
+        .split(",")
+        .map(|element| element.parse())
+        .collect()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let positions = parse_input(input)?;
+        let maximum_position = positions
+            .iter()
+            .max()
+            .ok_or_else(|| SolveError::unsolvable("no crab positions"))?;
+
+        (0..=*maximum_position)
+            .map(|target| {
+                positions
+                    .iter()
+                    .map(|position| (target - position).abs())
+                    .sum::<isize>()
+            })
+            .min()
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::unsolvable("no crab positions"))
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let positions = parse_input(input)?;
+        let maximum_position = positions
+            .iter()
+            .max()
+            .ok_or_else(|| SolveError::unsolvable("no crab positions"))?;
+
+        (0..=*maximum_position)
+            .map(|target| {
+                positions
+                    .iter()
+                    .map(|position| {
+                        let distance = (target - position).abs();
+                        distance * (distance + 1) / 2
+                    })
+                    .sum::<isize>()
+            })
+            .min()
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::unsolvable("no crab positions"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_tests!(Day07, part_one = 37, part_two = 168);
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day07::part_one("16,1,x").unwrap_err().to_string(),
+            "parse error at line 1, column 6: invalid digit found in string, found \"x\""
+        );
+        assert_eq!(
+            Day07::part_two("").unwrap_err().to_string(),
+            "parse error at line 1, column 1: cannot parse integer from empty string, found nothing"
+        );
+    }
+}
@@ -0,0 +1,206 @@
+use crate::parser::{self, ParseError, Span};
+use crate::solution::{Answer, Solution, SolveError};
+use std::collections::HashSet;
+
+#[derive(Clone, PartialEq, Eq)]
+struct SevenSegmentNumber {
+    activated_lines: HashSet<char>,
+}
+
+impl SevenSegmentNumber {
+    fn new(lines: String) -> Self {
+        Self {
+            activated_lines: lines.chars().collect(),
+        }
+    }
+
+    pub fn size(&self) -> usize {
+        self.activated_lines.len()
+    }
+
+    pub fn overlap(&self, other: &Self) -> usize {
+        other
+            .activated_lines
+            .iter()
+            .filter(|char| self.activated_lines.contains(char))
+            .count()
+    }
+
+    pub fn contains(&self, other: &Self) -> bool {
+        other
+            .activated_lines
+            .iter()
+            .all(|char| self.activated_lines.contains(char))
+    }
+}
+
+/// The ten unique signal patterns and the four output digits of a display.
+struct Entry {
+    patterns: Vec<SevenSegmentNumber>,
+    output: Vec<SevenSegmentNumber>,
+}
+
+impl Entry {
+    /// Deduces the wiring from the patterns and reads the four output digits as a number.
+    fn decode(&self) -> Result<usize, SolveError> {
+        let unsolvable = || SolveError::unsolvable("the patterns have no consistent wiring");
+        let input = &self.patterns;
+
+        let one = input
+            .iter()
+            .find(|element| element.size() == 2)
+            .ok_or_else(unsolvable)?;
+        let four = input
+            .iter()
+            .find(|element| element.size() == 4)
+            .ok_or_else(unsolvable)?;
+        let seven = input
+            .iter()
+            .find(|element| element.size() == 3)
+            .ok_or_else(unsolvable)?;
+        let eight = input
+            .iter()
+            .find(|element| element.size() == 7)
+            .ok_or_else(unsolvable)?;
+
+        let three = input
+            .iter()
+            .filter(|element| element.size() == 5)
+            .filter(|element| element.contains(one))
+            .find(|element| element.contains(seven))
+            .ok_or_else(unsolvable)?;
+
+        let nine = input
+            .iter()
+            .filter(|element| element.size() == 6)
+            .filter(|element| element.contains(three))
+            .find(|element| element.contains(four))
+            .ok_or_else(unsolvable)?;
+
+        let two = input
+            .iter()
+            .filter(|element| element.size() == 5)
+            .filter(|element| element.overlap(nine) == 4)
+            .find(|element| *element != three)
+            .ok_or_else(unsolvable)?;
+
+        let five = input
+            .iter()
+            .filter(|element| element.size() == 5)
+            .filter(|element| *element != two)
+            .find(|element| *element != three)
+            .ok_or_else(unsolvable)?;
+
+        let zero = input
+            .iter()
+            .filter(|element| element.size() == 6)
+            .filter(|element| element.contains(one))
+            .find(|element| *element != nine)
+            .ok_or_else(unsolvable)?;
+
+        let six = input
+            .iter()
+            .filter(|element| element.size() == 6)
+            .filter(|element| *element != zero)
+            .find(|element| *element != nine)
+            .ok_or_else(unsolvable)?;
+
+        let digits = [zero, one, two, three, four, five, six, seven, eight, nine];
+        let distinct = digits
+            .iter()
+            .enumerate()
+            .all(|(index, digit)| !digits[index + 1..].contains(digit));
+        if !distinct {
+            return Err(unsolvable());
+        }
+
+        self.output.iter().try_fold(0, |number, element| {
+            let digit = digits
+                .iter()
+                .position(|digit| *digit == element)
+                .ok_or_else(unsolvable)?;
+            Ok(number * 10 + digit)
+        })
+    }
+}
+
+fn parse_patterns(span: Span, count: usize) -> Result<Vec<SevenSegmentNumber>, ParseError> {
+    let patterns = span
+        .split_whitespace()
+        .map(|pattern| {
+            let segments = pattern.as_str();
+            match segments.chars().all(|c| ('a'..='g').contains(&c)) {
+                true => Ok(SevenSegmentNumber::new(segments.to_string())),
+                false => Err(pattern.error("expected segments a to g")),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match patterns.len() == count {
+        true => Ok(patterns),
+        false => Err(span.error(format!("expected {count} patterns"))),
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Entry>, ParseError> {
+    parser::lines(input)
+        .map(|line| {
+            let (patterns, output) = line.split_once(" | ")?;
+            Ok(Entry {
+                patterns: parse_patterns(patterns, 10)?,
+                output: parse_patterns(output, 4)?,
+            })
+        })
+        .collect()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        Ok(Answer::from(
+            parse_input(input)?
+                .iter()
+                .map(|entry| {
+                    entry
+                        .output
+                        .iter()
+                        .filter(|element| matches!(element.size(), 2 | 3 | 4 | 7))
+                        .count()
+                })
+                .sum::<usize>() as u32,
+        ))
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let mut sum = 0;
+        for entry in parse_input(input)? {
+            sum += entry.decode()?;
+        }
+
+        Ok(Answer::from(sum as u32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_tests!(Day08, part_one = 26, part_two = 61229);
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day08::part_one("ab cd | ef").unwrap_err().to_string(),
+            "parse error at line 1, column 1: expected 10 patterns, found \"ab cd\""
+        );
+        assert_eq!(
+            Day08::part_two("a b c d e f g ab abc abcd | ab xy abc abcd")
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 1, column 32: expected segments a to g, found \"xy\""
+        );
+    }
+}
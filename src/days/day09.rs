@@ -0,0 +1,115 @@
+use crate::parser::{self, ParseError};
+use crate::solution::{Answer, Solution, SolveError};
+use itertools::Itertools;
+use std::collections::{HashSet, VecDeque};
+
+fn parse_input(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    parser::grid(input, |c| c.to_digit(10))
+}
+
+fn neighbours(point: (usize, usize), width: usize, height: usize) -> Vec<(usize, usize)> {
+    let mut neighbours = vec![];
+
+    let (i, j) = point;
+
+    if i > 0 {
+        neighbours.push((i - 1, j));
+    }
+
+    if i < width - 1 {
+        neighbours.push((i + 1, j));
+    }
+
+    if j > 0 {
+        neighbours.push((i, j - 1));
+    }
+
+    if j < height - 1 {
+        neighbours.push((i, j + 1));
+    }
+
+    neighbours
+}
+
+fn find_low_point_coordinates(grid: &[Vec<u32>]) -> Vec<(usize, usize)> {
+    let width = grid.len();
+    let height = grid[0].len();
+
+    grid.iter()
+        .enumerate()
+        .flat_map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(j, element)| {
+                    neighbours((i, *j), width, height)
+                        .into_iter()
+                        .all(|(i, j)| grid[i][j] > **element)
+                })
+                .map(|(j, _)| (i, j))
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let grid = parse_input(input)?;
+        let low_point_coordinates = find_low_point_coordinates(&grid);
+
+        Ok(Answer::from(
+            low_point_coordinates
+                .into_iter()
+                .map(|(i, j)| grid[i][j] + 1)
+                .sum::<u32>(),
+        ))
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let grid = parse_input(input)?;
+        let width = grid.len();
+        let height = grid[0].len();
+
+        let low_point_coordinates = find_low_point_coordinates(&grid);
+
+        Ok(Answer::from(
+            low_point_coordinates
+                .into_iter()
+                .map(|(i, j)| {
+                    let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+                    queue.push_front((i, j));
+
+                    let mut visited_nodes: HashSet<(usize, usize)> = HashSet::new();
+
+                    while let Some((i, j)) = queue.pop_front() {
+                        if visited_nodes.contains(&(i, j)) {
+                            continue;
+                        }
+
+                        if grid[i][j] == 9 {
+                            continue;
+                        }
+
+                        visited_nodes.insert((i, j));
+                        queue.extend(neighbours((i, j), width, height));
+                    }
+
+                    visited_nodes.len() as u32
+                })
+                .sorted()
+                .rev()
+                .take(3)
+                .product::<u32>(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_tests!(Day09, part_one = 15, part_two = 1134);
+}
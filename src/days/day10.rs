@@ -0,0 +1,125 @@
+use crate::parser::{self, ParseError, Span};
+use crate::solution::{Answer, Solution, SolveError};
+use itertools::Itertools;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Character {
+    Round,
+    Block,
+    Curly,
+    Triangle,
+}
+
+enum Type {
+    Open,
+    Closed,
+}
+
+enum Line {
+    Corrupted(Character),
+    Incomplete(Vec<Character>),
+}
+
+fn parse_line(line: Span) -> Result<Line, ParseError> {
+    let mut stack: Vec<Character> = Vec::new();
+
+    for (span, element) in line.chars() {
+        let (t, char) = match element {
+            '(' => (Type::Open, Character::Round),
+            ')' => (Type::Closed, Character::Round),
+            '[' => (Type::Open, Character::Block),
+            ']' => (Type::Closed, Character::Block),
+            '{' => (Type::Open, Character::Curly),
+            '}' => (Type::Closed, Character::Curly),
+            '<' => (Type::Open, Character::Triangle),
+            '>' => (Type::Closed, Character::Triangle),
+            _ => return Err(span.error("expected one of ()[]{}<>")),
+        };
+
+        match t {
+            Type::Open => stack.push(char),
+            Type::Closed => match stack.pop() {
+                Some(previous) if previous == char => {}
+                Some(_) => return Ok(Line::Corrupted(char)),
+                None => return Err(span.error("no open chunk to close")),
+            },
+        }
+    }
+
+    Ok(Line::Incomplete(stack))
+}
+
+fn parse_input(input: &str) -> Result<Vec<Line>, ParseError> {
+    parser::lines(input).map(parse_line).collect()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        Ok(Answer::from(
+            parse_input(input)?
+                .into_iter()
+                .filter_map(|line| match line {
+                    Line::Corrupted(char) => Some(char),
+                    Line::Incomplete(_) => None,
+                })
+                .map(|char| match char {
+                    Character::Round => 3,
+                    Character::Block => 57,
+                    Character::Curly => 1197,
+                    Character::Triangle => 25137,
+                })
+                .sum::<u32>(),
+        ))
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let sorted_scores = parse_input(input)?
+            .into_iter()
+            .filter_map(|line| match line {
+                Line::Corrupted(_) => None,
+                Line::Incomplete(stack) => Some(stack),
+            })
+            .map(|chars| {
+                chars
+                    .iter()
+                    .rev()
+                    .map(|char| match char {
+                        Character::Round => 1,
+                        Character::Block => 2,
+                        Character::Curly => 3,
+                        Character::Triangle => 4,
+                    })
+                    .fold(0, |acc, amount| acc * 5 + amount)
+            })
+            .sorted()
+            .collect::<Vec<usize>>();
+
+        match sorted_scores.get(sorted_scores.len() / 2) {
+            Some(score) => Ok(Answer::from(*score)),
+            None => Err(SolveError::unsolvable("no incomplete lines")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_tests!(Day10, part_one = 26397, part_two = 288957);
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day10::part_one("[]\n(<x>)").unwrap_err().to_string(),
+            "parse error at line 2, column 3: expected one of ()[]{}<>, found \"x\""
+        );
+        assert_eq!(
+            Day10::part_two("()]").unwrap_err().to_string(),
+            "parse error at line 1, column 3: no open chunk to close, found \"]\""
+        );
+    }
+}
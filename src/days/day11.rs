@@ -0,0 +1,99 @@
+use crate::parser::{self, ParseError};
+use crate::solution::{Answer, Solution, SolveError};
+use std::collections::{HashSet, VecDeque};
+
+fn neighbours(point: (usize, usize), width: usize, height: usize) -> Vec<(usize, usize)> {
+    let (i, j) = (point.0 as isize, point.1 as isize);
+    let neighbours: Vec<(isize, isize)> = vec![
+        (i - 1, j),
+        (i - 1, j - 1),
+        (i - 1, j + 1),
+        (i + 1, j),
+        (i + 1, j - 1),
+        (i + 1, j + 1),
+        (i, j + 1),
+        (i, j - 1),
+    ];
+
+    neighbours
+        .into_iter()
+        .filter(|(i, j)| *i >= 0 && *j >= 0 && *i < width as isize && *j < height as isize)
+        .map(|(i, j)| (i as usize, j as usize))
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<u32>>, ParseError> {
+    parser::grid(input, |c| c.to_digit(10))
+}
+
+fn next_step(grid: &mut [Vec<u32>]) -> usize {
+    let width = grid.len();
+    let height = grid[0].len();
+
+    let mut queue: VecDeque<(usize, usize)> = VecDeque::new();
+    let mut flashing_octopus: HashSet<(usize, usize)> = HashSet::new();
+
+    for (i, row) in grid.iter_mut().enumerate() {
+        for (j, element) in row.iter_mut().enumerate() {
+            *element += 1;
+
+            if *element > 9 {
+                queue.extend(neighbours((i, j), width, height));
+                flashing_octopus.insert((i, j));
+            }
+        }
+    }
+
+    while let Some((i, j)) = queue.pop_front() {
+        if flashing_octopus.contains(&(i, j)) {
+            continue;
+        }
+
+        grid[i][j] += 1;
+        if grid[i][j] > 9 {
+            queue.extend(neighbours((i, j), width, height));
+            flashing_octopus.insert((i, j));
+        }
+    }
+
+    for row in grid.iter_mut() {
+        for element in row.iter_mut() {
+            if *element > 9 {
+                *element = 0;
+            }
+        }
+    }
+
+    flashing_octopus.len()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let mut grid = parse_input(input)?;
+
+        Ok(Answer::from(
+            (0..100).map(|_| next_step(&mut grid)).sum::<usize>() as u32,
+        ))
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let mut grid = parse_input(input)?;
+
+        (1..1000)
+            .map(|i| (i, next_step(&mut grid)))
+            .find(|(_, number_of_flashing_octopus)| *number_of_flashing_octopus == 10 * 10)
+            .map(|(iteration, _)| iteration.into())
+            .ok_or_else(|| SolveError::unsolvable("octopuses don't flash simultaneously"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_tests!(Day11, part_one = 1656, part_two = 195);
+}
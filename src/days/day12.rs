@@ -0,0 +1,171 @@
+use crate::parser::{self, ParseError, Span};
+use crate::solution::{Answer, Solution, SolveError};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+#[derive(Clone, Eq, Hash, PartialEq, Debug)]
+enum Cave {
+    Start,
+    End,
+    Small(String),
+    Large(String),
+}
+
+fn parse_string_to_cave(span: Span) -> Result<Cave, ParseError> {
+    let input = span.as_str();
+    if input == "start" {
+        return Ok(Cave::Start);
+    }
+
+    if input == "end" {
+        return Ok(Cave::End);
+    }
+
+    match input.chars().next() {
+        Some(first) if first.is_uppercase() => Ok(Cave::Large(input.to_string())),
+        Some(_) => Ok(Cave::Small(input.to_string())),
+        None => Err(span.error("expected a cave")),
+    }
+}
+
+fn parse_input(input: &str) -> Result<HashMap<Cave, Vec<Cave>>, ParseError> {
+    let transitions = parser::lines(input)
+        .map(|line| {
+            let (departure, arrival) = line.split_once("-")?;
+            Ok((
+                parse_string_to_cave(departure)?,
+                parse_string_to_cave(arrival)?,
+            ))
+        })
+        .collect::<Result<HashSet<(Cave, Cave)>, ParseError>>()?;
+
+    let nodes = transitions
+        .iter()
+        .flat_map(|(departure, arrival)| vec![departure.clone(), arrival.clone()])
+        .unique()
+        .collect::<Vec<Cave>>();
+
+    Ok(nodes
+        .iter()
+        .map(|departure| {
+            (
+                departure.to_owned(),
+                nodes
+                    .iter()
+                    .filter(|arrival| arrival != &&Cave::Start)
+                    .filter(|arrival| {
+                        transitions.contains(&(departure.clone(), (*arrival).to_owned()))
+                            || transitions.contains(&((*arrival).to_owned(), departure.clone()))
+                    })
+                    .map(|node| node.to_owned())
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect())
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let neighbours = parse_input(input)?;
+
+        let mut solutions: Vec<Vec<Cave>> = Vec::new();
+        let mut queue = VecDeque::new();
+        queue.push_front(vec![Cave::Start]);
+
+        while let Some(path) = queue.pop_front() {
+            let last_node = path.last().unwrap();
+
+            if last_node == &Cave::End {
+                solutions.push(path);
+                continue;
+            }
+
+            for neighbour in neighbours.get(last_node).into_iter().flatten() {
+                match neighbour {
+                    // paths never go back to the start, see `parse_input`.
+                    Cave::Start => continue,
+                    Cave::End | Cave::Large(_) => {}
+                    Cave::Small(_) => {
+                        if path.contains(neighbour) {
+                            continue;
+                        }
+                    }
+                }
+
+                let mut path = path.clone();
+                path.push(neighbour.clone());
+                queue.push_front(path);
+            }
+        }
+
+        Ok(Answer::from(solutions.len() as u32))
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let neighbours = parse_input(input)?;
+
+        let mut solutions: Vec<Vec<Cave>> = Vec::new();
+        let mut queue = VecDeque::new();
+        queue.push_front((vec![Cave::Start], false));
+
+        while let Some((path, visited_small_node_twice)) = queue.pop_front() {
+            let last_node = path.last().unwrap();
+
+            if last_node == &Cave::End {
+                solutions.push(path);
+                continue;
+            }
+
+            for neighbour in neighbours.get(last_node).into_iter().flatten() {
+                match neighbour {
+                    // paths never go back to the start, see `parse_input`.
+                    Cave::Start => continue,
+                    Cave::End | Cave::Large(_) => {
+                        let mut path = path.clone();
+                        path.push(neighbour.clone());
+                        queue.push_front((path, visited_small_node_twice));
+                    }
+                    Cave::Small(_) => {
+                        let will_visit_small_node_again = path.contains(neighbour);
+
+                        if will_visit_small_node_again && visited_small_node_twice {
+                            continue;
+                        }
+
+                        let visited_small_node_twice =
+                            will_visit_small_node_again || visited_small_node_twice;
+
+                        let mut path = path.clone();
+                        path.push(neighbour.clone());
+                        queue.push_front((path, visited_small_node_twice));
+                    }
+                };
+            }
+        }
+
+        Ok(Answer::from(solutions.len() as u32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_tests!(Day12, part_one = examples, part_two = examples);
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day12::part_one("start-A\nA-").unwrap_err().to_string(),
+            "parse error at line 2, column 3: expected a cave, found nothing"
+        );
+        assert_eq!(
+            Day12::part_two("start-A\nA end").unwrap_err().to_string(),
+            "parse error at line 2, column 1: expected \"-\", found \"A end\""
+        );
+    }
+}
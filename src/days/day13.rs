@@ -0,0 +1,147 @@
+use crate::parser::{ParseError, Span};
+use crate::solution::{Answer, Solution, SolveError};
+use itertools::Itertools;
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, Copy)]
+enum FoldInstruction {
+    X(usize),
+    Y(usize),
+}
+
+struct Paper {
+    points: Vec<(usize, usize)>,
+}
+
+impl Paper {
+    pub fn new(points: Vec<(usize, usize)>) -> Self {
+        Self { points }
+    }
+
+    pub fn apply(&self, fold_instruction: FoldInstruction) -> Self {
+        let points = self
+            .points
+            .iter()
+            .cloned()
+            .map(|(x, y)| match fold_instruction {
+                FoldInstruction::X(line) => match x.cmp(&line) {
+                    Ordering::Less => (x, y),
+                    Ordering::Equal | Ordering::Greater => (2 * line - x, y),
+                },
+                FoldInstruction::Y(line) => match y.cmp(&line) {
+                    Ordering::Less => (x, y),
+                    Ordering::Equal | Ordering::Greater => (x, 2 * line - y),
+                },
+            })
+            .unique()
+            .collect::<Vec<_>>();
+
+        Self { points }
+    }
+
+    pub fn render(&self) -> String {
+        let width = self
+            .points
+            .iter()
+            .map(|(x, _)| *x)
+            .max()
+            .unwrap_or_default();
+        let height = self
+            .points
+            .iter()
+            .map(|(_, y)| *y)
+            .max()
+            .unwrap_or_default();
+
+        (0..=height)
+            .map(|j| {
+                (0..=width)
+                    .map(|i| {
+                        if self.points.contains(&(i, j)) {
+                            '#'
+                        } else {
+                            '.'
+                        }
+                    })
+                    .collect::<String>()
+            })
+            .join("\n")
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Paper, Vec<FoldInstruction>), ParseError> {
+    let (points, instructions) = Span::new(input).split_once("\n\n")?;
+    let points = points
+        .lines()
+        .map(|line| {
+            let (x, y) = line.split_once(",")?;
+            Ok((x.parse()?, y.parse()?))
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    let instructions = instructions
+        .lines()
+        .map(|line| {
+            let (axis, line) = line.split_once("=")?;
+            match axis.as_str() {
+                "fold along x" => Ok(FoldInstruction::X(line.parse()?)),
+                "fold along y" => Ok(FoldInstruction::Y(line.parse()?)),
+                _ => Err(axis.error("expected \"fold along x\" or \"fold along y\"")),
+            }
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    Ok((Paper::new(points), instructions))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const MANUAL: &'static [u8] = &[2];
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let (paper, instructions) = parse_input(input)?;
+        let instruction = instructions
+            .first()
+            .ok_or_else(|| SolveError::unsolvable("there are no fold instructions"))?;
+        let result = paper.apply(*instruction);
+
+        Ok(Answer::from(result.points.len() as u32))
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let (paper, instructions) = parse_input(input)?;
+
+        let result = instructions
+            .iter()
+            .fold(paper, |paper, instruction| paper.apply(*instruction));
+
+        Ok(result.render().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_tests!(
+        Day13,
+        part_one = 17,
+        part_two = "#####\n#...#\n#...#\n#...#\n#####"
+    );
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day13::part_one("6,10\n0,14\n\nfold along z=7").unwrap_err().to_string(),
+            "parse error at line 4, column 1: expected \"fold along x\" or \"fold along y\", found \"fold along z\""
+        );
+        assert_eq!(
+            Day13::part_two("6,10\n0;14\n\nfold along y=7")
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 2, column 1: expected \",\", found \"0;14\""
+        );
+    }
+}
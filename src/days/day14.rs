@@ -0,0 +1,167 @@
+use crate::parser::{ParseError, Span};
+use crate::solution::{Answer, Solution, SolveError};
+use itertools::{Itertools, MinMaxResult};
+use std::collections::HashMap;
+
+type InsertionRule = ((String, String), String);
+
+fn parse_elements(input: Span) -> Vec<String> {
+    input
+        .chars()
+        .map(|(_, element)| element.to_string())
+        .collect()
+}
+
+fn parse_input(input: &str) -> Result<(Vec<String>, Vec<InsertionRule>), ParseError> {
+    let (template, rules) = Span::new(input).split_once("\n\n")?;
+    if template.is_empty() {
+        return Err(template.error("expected a polymer template"));
+    }
+    let template = parse_elements(template);
+
+    let rules = rules
+        .lines()
+        .map(|line| {
+            let (lhs, rhs) = line.split_once(" -> ")?;
+            let lhs = match parse_elements(lhs).as_slice() {
+                [first, second] => (first.clone(), second.clone()),
+                _ => return Err(lhs.error("expected a pair of elements")),
+            };
+
+            Ok((lhs, rhs.as_str().to_string()))
+        })
+        .collect::<Result<_, ParseError>>()?;
+
+    Ok((template, rules))
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let (template, rules) = parse_input(input)?;
+
+        let mut template = template;
+
+        for _ in 1..=10 {
+            let mut result: Vec<String> = template
+                .iter()
+                .tuple_windows()
+                .flat_map(|(lhs, rhs)| {
+                    let rule = rules
+                        .iter()
+                        .filter(|((possible_lhs, possible_rhs), _)| {
+                            possible_lhs == lhs && possible_rhs == rhs
+                        })
+                        .map(|(_, insertion)| insertion.clone())
+                        .next();
+
+                    match rule {
+                        Some(insertion) => vec![lhs.clone(), insertion],
+                        None => vec![lhs.clone()],
+                    }
+                })
+                .collect();
+            result.push(template.last().unwrap().clone());
+            template = result.clone();
+        }
+
+        let occurrences_and_frequency =
+            template
+                .iter()
+                .fold(HashMap::<String, usize>::new(), |mut map, element| {
+                    *map.entry(element.clone()).or_default() += 1;
+                    map
+                });
+
+        let frequencies = occurrences_and_frequency.values().collect::<Vec<_>>();
+
+        match frequencies.iter().minmax() {
+            MinMaxResult::MinMax(min, max) => Ok(Answer::from(**max - **min)),
+            _ => Err(SolveError::unsolvable(
+                "polymer has fewer than two elements",
+            )),
+        }
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let (input, rules) = parse_input(input)?;
+
+        let mut template = input.clone().into_iter().tuple_windows().fold(
+            HashMap::<(String, String), usize>::new(),
+            |mut map, element: (String, String)| {
+                *map.entry(element.clone()).or_default() += 1;
+                map
+            },
+        );
+
+        for _ in 1..=40 {
+            let mut result = HashMap::new();
+
+            for (pair, amount) in template {
+                let rule = rules
+                    .iter()
+                    .filter(|(possible_match, _)| *possible_match == pair)
+                    .map(|(_, insertion)| insertion.clone())
+                    .next();
+
+                match rule {
+                    Some(insertion) => {
+                        *result.entry((pair.0, insertion.clone())).or_default() += amount;
+                        *result.entry((insertion.clone(), pair.1)).or_default() += amount;
+                    }
+                    None => *result.entry(pair).or_default() += amount,
+                };
+            }
+
+            template = result;
+        }
+
+        let mut frequencies: HashMap<String, usize> = HashMap::new();
+
+        *frequencies
+            .entry(input.clone().first().unwrap().clone())
+            .or_default() += 1;
+        for ((lhs, rhs), amount) in template {
+            *frequencies.entry(lhs).or_default() += amount;
+            *frequencies.entry(rhs).or_default() += amount;
+        }
+        *frequencies
+            .entry(input.clone().last().unwrap().clone())
+            .or_default() += 1;
+
+        match frequencies.values().minmax() {
+            MinMaxResult::MinMax(min, max) => Ok(Answer::from(*max / 2 - *min / 2)),
+            _ => Err(SolveError::unsolvable(
+                "polymer has fewer than two elements",
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_tests!(Day14, part_one = 1588, part_two = 2188189693529_u64);
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day14::part_one("NNCB\n\nCH -> B\nHHH -> N")
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 4, column 1: expected a pair of elements, found \"HHH\""
+        );
+        assert_eq!(
+            Day14::part_two("NNCB\nCH -> B").unwrap_err().to_string(),
+            "parse error at line 1, column 1: expected \"\\n\\n\", found \"NNCB\\nCH -> B\""
+        );
+        assert_eq!(
+            Day14::part_two("\n\nCH -> B").unwrap_err().to_string(),
+            "parse error at line 1, column 1: expected a polymer template, found nothing"
+        );
+    }
+}
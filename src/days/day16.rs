@@ -0,0 +1,317 @@
+use crate::parser::{ParseError, Span};
+use crate::solution::{Answer, Solution, SolveError};
+use itertools::Itertools;
+
+struct BinaryStream {
+    binary: Vec<bool>,
+    pointer: usize,
+}
+
+impl BinaryStream {
+    pub fn from_hexadecimal(input: Span) -> Result<Self, ParseError> {
+        Ok(Self::from_binary(hexadecimal_to_binary(input)?))
+    }
+
+    pub fn from_binary(input: Vec<bool>) -> Self {
+        Self {
+            binary: input,
+            pointer: 0,
+        }
+    }
+
+    pub fn to_decimal(&self) -> usize {
+        self.binary
+            .iter()
+            .rev()
+            .enumerate()
+            .filter(|(_, bool)| **bool)
+            .map(|(index, _)| 2_usize.pow(index as u32))
+            .sum()
+    }
+
+    pub fn to_bool(&self) -> bool {
+        self.to_decimal() != 0
+    }
+
+    pub fn read_next(&mut self, size: usize) -> BinaryStream {
+        let result = Self::from_binary(
+            self.binary
+                .iter()
+                .skip(self.pointer)
+                .take(size)
+                .cloned()
+                .collect(),
+        );
+        self.pointer += size;
+        result
+    }
+
+    pub fn has_next(&self) -> bool {
+        self.binary.iter().skip(self.pointer).len() >= 11
+    }
+
+    /// Whether more bits were read than the stream holds.
+    pub fn is_exhausted(&self) -> bool {
+        self.pointer > self.binary.len()
+    }
+}
+
+fn hexadecimal_to_binary(input: Span) -> Result<Vec<bool>, ParseError> {
+    input
+        .chars()
+        .map(|(span, char)| match char.to_digit(16) {
+            Some(digit) => Ok((0..4).rev().map(move |bit| digit & (1 << bit) != 0)),
+            None => Err(span.error("expected a hexadecimal digit")),
+        })
+        .flatten_ok()
+        .collect()
+}
+
+#[derive(Debug, PartialEq)]
+enum Package {
+    Literal {
+        version: usize,
+        literal: usize,
+    },
+    Operation {
+        version: usize,
+        operator_type: usize,
+        content: Vec<Package>,
+    },
+}
+
+impl Package {
+    pub fn version_sum(&self) -> usize {
+        match self {
+            Package::Literal { version, .. } => *version,
+            Package::Operation {
+                version, content, ..
+            } => {
+                *version
+                    + content
+                        .iter()
+                        .map(|package| package.version_sum())
+                        .sum::<usize>()
+            }
+        }
+    }
+
+    pub fn value(&self) -> Result<usize, SolveError> {
+        match self {
+            Package::Literal { literal, .. } => Ok(*literal),
+            Package::Operation {
+                operator_type,
+                content,
+                ..
+            } => {
+                let values = content
+                    .iter()
+                    .map(|package| package.value())
+                    .collect::<Result<Vec<_>, _>>()?;
+                let invalid = || {
+                    SolveError::unsolvable(format!(
+                        "a packet of type {operator_type} cannot have {} sub-packets",
+                        values.len()
+                    ))
+                };
+
+                match (operator_type, values.as_slice()) {
+                    (0, _) => Ok(values.iter().sum()),
+                    (1, _) => Ok(values.iter().product()),
+                    (2, _) => values.iter().min().copied().ok_or_else(invalid),
+                    (3, _) => values.iter().max().copied().ok_or_else(invalid),
+                    (5, [first, second]) => Ok((first > second) as usize),
+                    (6, [first, second]) => Ok((first < second) as usize),
+                    (7, [first, second]) => Ok((first == second) as usize),
+                    _ => Err(invalid()),
+                }
+            }
+        }
+    }
+}
+
+fn parse_packages(binary: &mut BinaryStream) -> Package {
+    let packet_version = binary.read_next(3).to_decimal();
+    let packet_type = binary.read_next(3).to_decimal();
+
+    match packet_type {
+        4 => {
+            let mut literal_groups: Vec<BinaryStream> = Vec::new();
+            let mut final_literal_found = false;
+
+            while !final_literal_found {
+                final_literal_found = !binary.read_next(1).to_bool();
+                literal_groups.push(binary.read_next(4))
+            }
+
+            Package::Literal {
+                version: packet_version,
+                literal: BinaryStream::from_binary(
+                    literal_groups
+                        .into_iter()
+                        .flat_map(|binary| binary.binary)
+                        .collect::<Vec<bool>>(),
+                )
+                .to_decimal(),
+            }
+        }
+        _ => match binary.read_next(1).to_bool() {
+            false => {
+                let content_length = binary.read_next(15).to_decimal();
+                let mut content_binary = binary.read_next(content_length);
+                let mut content: Vec<Package> = Vec::new();
+
+                while content_binary.has_next() {
+                    content.push(parse_packages(&mut content_binary));
+                }
+
+                Package::Operation {
+                    version: packet_version,
+                    operator_type: packet_type,
+                    content,
+                }
+            }
+            true => {
+                let number_of_packages = binary.read_next(11).to_decimal();
+                let mut content: Vec<Package> = Vec::new();
+
+                while content.len() != number_of_packages {
+                    content.push(parse_packages(binary));
+                }
+
+                Package::Operation {
+                    version: packet_version,
+                    operator_type: packet_type,
+                    content,
+                }
+            }
+        },
+    }
+}
+
+fn parse_input(input: &str) -> Result<Package, ParseError> {
+    let input = Span::new(input).trim();
+    let mut binary = BinaryStream::from_hexadecimal(input)?;
+    let package = parse_packages(&mut binary);
+
+    match binary.is_exhausted() {
+        true => Err(input.error("the transmission ends in the middle of a packet")),
+        false => Ok(package),
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let package = parse_input(input)?;
+
+        Ok(Answer::from(package.version_sum() as u32))
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let package = parse_input(input)?;
+
+        Ok(package.value()?.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hexadecimal_to_binary() {
+        assert_eq!(
+            hexadecimal_to_binary(Span::new("D2FE28"))
+                .unwrap()
+                .into_iter()
+                .map(|element| element as u32)
+                .collect::<Vec<_>>(),
+            vec![1, 1, 0, 1, 0, 0, 1, 0, 1, 1, 1, 1, 1, 1, 1, 0, 0, 0, 1, 0, 1, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_parse_package_literal_1() {
+        let mut binary_stream = BinaryStream::from_hexadecimal(Span::new("D2FE28")).unwrap();
+        let package = parse_packages(&mut binary_stream);
+        assert_eq!(
+            package,
+            Package::Literal {
+                version: 6,
+                literal: 2021
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_package_literal_2() {
+        let mut binary_stream =
+            BinaryStream::from_hexadecimal(Span::new("38006F45291200")).unwrap();
+        let package = parse_packages(&mut binary_stream);
+
+        assert_eq!(
+            package,
+            Package::Operation {
+                version: 1,
+                operator_type: 6,
+                content: vec![
+                    Package::Literal {
+                        version: 6,
+                        literal: 10
+                    },
+                    Package::Literal {
+                        version: 2,
+                        literal: 20
+                    },
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_package_literal_3() {
+        let mut binary_stream =
+            BinaryStream::from_hexadecimal(Span::new("EE00D40C823060")).unwrap();
+        let package = parse_packages(&mut binary_stream);
+
+        assert_eq!(
+            package,
+            Package::Operation {
+                version: 7,
+                operator_type: 3,
+                content: vec![
+                    Package::Literal {
+                        version: 2,
+                        literal: 1
+                    },
+                    Package::Literal {
+                        version: 4,
+                        literal: 2
+                    },
+                    Package::Literal {
+                        version: 1,
+                        literal: 3
+                    },
+                ]
+            }
+        );
+    }
+
+    crate::aoc_tests!(Day16, part_one = examples, part_two = examples);
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day16::part_one("D2FX28").unwrap_err().to_string(),
+            "parse error at line 1, column 4: expected a hexadecimal digit, found \"X\""
+        );
+        assert_eq!(
+            Day16::part_one("D2F").unwrap_err().to_string(),
+            "parse error at line 1, column 1: the transmission ends in the middle of a packet, found \"D2F\""
+        );
+    }
+}
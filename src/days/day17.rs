@@ -0,0 +1,79 @@
+use crate::parser::{ParseError, Span};
+use crate::solution::{Answer, Solution, SolveError};
+
+/// The x and y ranges of the target area.
+type Target = ((isize, isize), (isize, isize));
+
+fn parse_range(range: Span, prefix: &str) -> Result<(isize, isize), ParseError> {
+    let (start, end) = range.strip_prefix(prefix)?.split_once("..")?;
+    Ok((start.parse()?, end.parse()?))
+}
+
+fn parse_input(input: &str) -> Result<Target, ParseError> {
+    let input = Span::new(input).trim();
+    let (x, y) = input.strip_prefix("target area: ")?.split_once(", ")?;
+
+    Ok((parse_range(x, "x=")?, parse_range(y, "y=")?))
+}
+
+fn will_hit_target(vx: isize, vy: isize, x: isize, y: isize, target: Target) -> bool {
+    let ((tx1, tx2), (ty1, ty2)) = target;
+
+    if x > tx2 {
+        return false;
+    }
+
+    if y < ty1 {
+        return false;
+    }
+
+    if x >= tx1 && y <= ty2 {
+        return true;
+    }
+
+    will_hit_target(vx - vx.signum(), vy - 1, x + vx, y + vy, target)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let (_, (y, _)) = parse_input(input)?;
+
+        Ok(Answer::from((y * (y + 1)) / 2))
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let target = parse_input(input)?;
+        let ((_, x2), (y1, _)) = target;
+
+        Ok(Answer::from(
+            (1..=x2)
+                .map(|x| {
+                    (y1..-y1)
+                        .filter(|y| will_hit_target(x, *y, 0, 0, target))
+                        .count()
+                })
+                .sum::<usize>(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_tests!(Day17, part_one = 45, part_two = 112);
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day17::part_one("target area: x=20..30, z=-10..-5")
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 1, column 24: expected \"y=\", found \"z=-10..-5\""
+        );
+    }
+}
@@ -0,0 +1,243 @@
+use crate::parser::{self, ParseError, Span};
+use crate::solution::{Answer, Solution, SolveError};
+use itertools::Itertools;
+use std::cmp::max;
+
+fn parse_number(input: Span) -> Result<Vec<(usize, usize)>, ParseError> {
+    let mut depth: usize = 0;
+    let mut number: Vec<(usize, usize)> = vec![];
+
+    for (span, char) in input.chars() {
+        match char {
+            '[' => depth += 1,
+            ']' => {
+                depth = depth
+                    .checked_sub(1)
+                    .ok_or_else(|| span.error("expected a matching \"[\""))?
+            }
+            ',' => continue,
+            _ => number.push((span.parse()?, depth)),
+        }
+    }
+
+    Ok(number)
+}
+
+fn parse_input(input: &str) -> Result<Vec<Vec<(usize, usize)>>, ParseError> {
+    parser::lines(input).map(parse_number).collect()
+}
+
+fn add(lhs: &[(usize, usize)], rhs: &[(usize, usize)]) -> Vec<(usize, usize)> {
+    let mut result = vec![];
+
+    for (value, depth) in lhs {
+        result.push((*value, *depth + 1));
+    }
+
+    for (value, depth) in rhs {
+        result.push((*value, *depth + 1));
+    }
+
+    reduce(result)
+}
+
+fn reduce(number: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+    // First check if there is anything that needs to be exploded
+    if let Some((index, (lhs, depth))) =
+        number.iter().enumerate().find(|(_, (_, depth))| depth > &4)
+    {
+        assert_eq!(depth, &5);
+        let mut number = number.clone();
+
+        let (rhs, other_depth) = number[index + 1];
+        assert_eq!(other_depth, 5);
+
+        // the pair's left value is added to the first regular number to the left of the exploding pair (if any),
+        if index > 0 {
+            number[index - 1].0 += lhs;
+        }
+
+        // and the pair's right value is added to the first regular number to the right of the exploding pair (if any).
+        if number.len() > index + 2 {
+            number[index + 2].0 += rhs;
+        }
+
+        // Exploding pairs will always consist of two regular numbers.
+        // Then, the entire exploding pair is replaced with the regular number 0.
+        number[index] = (0, depth - 1);
+        number.remove(index + 1);
+
+        return reduce(number);
+    }
+
+    // Then check if there is a number that is too large
+    if let Some((index, (split, depth))) =
+        number.iter().enumerate().find(|(_, (value, _))| value > &9)
+    {
+        let mut number = number.clone();
+
+        // the left element of the pair should be the regular number divided by two and rounded down
+        number[index] = (split / 2, depth + 1);
+        // the right element of the pair should be the regular number divided by two and rounded up
+        number.insert(index + 1, (split.div_ceil(2), depth + 1));
+
+        return reduce(number);
+    }
+
+    number
+}
+
+fn magnitude(number: Vec<(usize, usize)>) -> usize {
+    let number = _fold(number, 4);
+    assert_eq!(number.len(), 1);
+
+    number[0].0
+}
+
+fn _fold(number: Vec<(usize, usize)>, depth: usize) -> Vec<(usize, usize)> {
+    let same_depth_pair: Option<(usize, (usize, usize))> = number
+        .iter()
+        .tuple_windows()
+        .enumerate()
+        .filter(|(_, ((_, d1), (_, d2)))| d1 == d2 && *d1 == depth)
+        .map(|(index, ((v1, _), (v2, _)))| (index, (*v1, *v2)))
+        .next();
+
+    if let Some((index, (v1, v2))) = same_depth_pair {
+        let mut folded_number = number.clone();
+        folded_number.remove(index + 1);
+        folded_number.remove(index);
+        folded_number.insert(index, (3 * v1 + 2 * v2, depth - 1));
+
+        return _fold(folded_number, depth);
+    }
+
+    if depth > 0 {
+        return _fold(number, depth - 1);
+    }
+
+    number
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let numbers = parse_input(input)?;
+        let mut number: Option<Vec<(usize, usize)>> = None;
+
+        for rhs in numbers.into_iter() {
+            number = Some(match number {
+                None => rhs,
+                Some(lhs) => add(&lhs, &rhs),
+            });
+        }
+
+        number
+            .map(|number| magnitude(number).into())
+            .ok_or_else(|| SolveError::unsolvable("no snailfish numbers"))
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        parse_input(input)?
+            .iter()
+            .tuple_combinations()
+            .map(|(lhs, rhs)| max(magnitude(add(rhs, lhs)), magnitude(add(lhs, rhs))))
+            .max()
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::unsolvable("fewer than two snailfish numbers"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snailfish(input: &str) -> Vec<(usize, usize)> {
+        parse_number(Span::new(input)).unwrap()
+    }
+
+    #[test]
+    fn test_sum_simple() {
+        let number = add(&snailfish("[1,1]"), &snailfish("[2,2]"));
+        assert_eq!(number, snailfish("[[1,1],[2,2]]"));
+
+        let number = add(&number, &snailfish("[3,3]"));
+        assert_eq!(number, snailfish("[[[1,1],[2,2]],[3,3]]"));
+
+        let number = add(&number, &snailfish("[4,4]"));
+        assert_eq!(number, snailfish("[[[[1,1],[2,2]],[3,3]],[4,4]]"));
+
+        let number = add(&number, &snailfish("[5,5]"));
+        assert_eq!(number, snailfish("[[[[3,0],[5,3]],[4,4]],[5,5]]"));
+
+        let number = add(&number, &snailfish("[6,6]"));
+        assert_eq!(number, snailfish("[[[[5,0],[7,4]],[5,5]],[6,6]]"));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_sum() {
+        let number = add(
+            &snailfish("[[[0,[4,5]],[0,0]],[[[4,5],[2,6]],[9,5]]]"),
+            &snailfish("[7,[[[3,7],[4,3]],[[6,3],[8,8]]]]"),
+        );
+        assert_eq!(number, snailfish("[[[[4,0],[5,4]],[[7,7],[6,0]]],[[8,[7,7]],[[7,9],[5,0]]]]"));
+
+        let number = add(&number, &snailfish("[[2,[[0,8],[3,4]]],[[[6,7],1],[7,[1,6]]]]"));
+        assert_eq!(number, snailfish("[[[[6,7],[6,7]],[[7,7],[0,7]]],[[[8,7],[7,7]],[[8,8],[8,0]]]]"));
+
+        let number = add(&number, &snailfish("[[[[2,4],7],[6,[0,5]]],[[[6,8],[2,8]],[[2,1],[4,5]]]]"));
+        assert_eq!(number, snailfish("[[[[7,0],[7,7]],[[7,7],[7,8]]],[[[7,7],[8,8]],[[7,7],[8,7]]]]"));
+
+        let number = add(&number, &snailfish("[7,[5,[[3,8],[1,4]]]]"));
+        assert_eq!(number, snailfish("[[[[7,7],[7,8]],[[9,5],[8,7]]],[[[6,8],[0,8]],[[9,9],[9,0]]]]"));
+
+        let number = add(&number, &snailfish("[[2,[2,2]],[8,[8,1]]]"));
+        assert_eq!(number, snailfish("[[[[6,6],[6,6]],[[6,0],[6,7]]],[[[7,7],[8,9]],[8,[8,1]]]]"));
+
+        let number = add(&number, &snailfish("[2,9]"));
+        assert_eq!(number, snailfish("[[[[6,6],[7,7]],[[0,7],[7,7]]],[[[5,5],[5,6]],9]]"));
+
+        let number = add(&number, &snailfish("[1,[[[9,3],9],[[9,0],[0,7]]]]"));
+        assert_eq!(number, snailfish("[[[[7,8],[6,7]],[[6,8],[0,8]]],[[[7,7],[5,0]],[[5,5],[5,6]]]]"));
+
+        let number = add(&number, &snailfish("[[[5,[7,4]],7],1]"));
+        assert_eq!(number, snailfish("[[[[7,7],[7,7]],[[8,7],[8,7]]],[[[7,0],[7,7]],9]]"));
+
+        let number = add(&number, &snailfish("[[[[4,2],2],6],[8,7]]"));
+        assert_eq!(number, snailfish("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]"));
+    }
+
+    #[test]
+    #[rustfmt::skip]
+    fn test_magnitude() {
+        assert_eq!(magnitude(snailfish("[9,1]")), 29);
+        assert_eq!(magnitude(snailfish("[1,9]")), 21);
+        assert_eq!(magnitude(snailfish("[[9,1],[1,9]]")), 129);
+        assert_eq!(magnitude(snailfish("[[1,7],4]")), 59);
+        assert_eq!(magnitude(snailfish("[3,[1,7]]")), 43);
+        assert_eq!(magnitude(snailfish("[[1,2],[[3,4],5]]")), 143);
+        assert_eq!(magnitude(snailfish("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]")), 1384);
+        assert_eq!(magnitude(snailfish("[[[[1,1],[2,2]],[3,3]],[4,4]]")), 445);
+        assert_eq!(magnitude(snailfish("[[[[3,0],[5,3]],[4,4]],[5,5]]")), 791);
+        assert_eq!(magnitude(snailfish("[[[[5,0],[7,4]],[5,5]],[6,6]]")), 1137);
+        assert_eq!(magnitude(snailfish("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]")), 3488);
+    }
+
+    crate::aoc_tests!(Day18, part_one = 4140, part_two = 3993);
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day18::part_one("[1,2]\n[3,x]").unwrap_err().to_string(),
+            "parse error at line 2, column 4: invalid digit found in string, found \"x\""
+        );
+        assert_eq!(
+            Day18::part_two("[1,2]]").unwrap_err().to_string(),
+            "parse error at line 1, column 6: expected a matching \"[\", found \"]\""
+        );
+    }
+}
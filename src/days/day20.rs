@@ -0,0 +1,154 @@
+use crate::parser::{ParseError, Span};
+use crate::solution::{Answer, Solution, SolveError};
+use std::collections::HashSet;
+use std::ops::Range;
+
+const MARGIN: isize = 200;
+
+struct Image {
+    pixels_lit: HashSet<(isize, isize)>,
+    x_range: (isize, isize),
+    y_range: (isize, isize),
+}
+
+impl Image {
+    fn x_range(&self) -> Range<isize> {
+        self.x_range.0..self.x_range.1
+    }
+
+    fn enlarged_x_range(&self) -> Range<isize> {
+        self.x_range.0 - MARGIN..self.x_range.1 + MARGIN
+    }
+
+    fn y_range(&self) -> Range<isize> {
+        self.y_range.0..self.y_range.1
+    }
+
+    fn enlarged_y_range(&self) -> Range<isize> {
+        self.y_range.0 - MARGIN..self.y_range.1 + MARGIN
+    }
+
+    fn neighbours(&self, pixel: (isize, isize)) -> impl Iterator<Item = (isize, isize)> {
+        let (i, j) = pixel;
+
+        [
+            (i - 1, j - 1),
+            (i, j - 1),
+            (i + 1, j - 1),
+            (i - 1, j),
+            (i, j),
+            (i + 1, j),
+            (i - 1, j + 1),
+            (i, j + 1),
+            (i + 1, j + 1),
+        ]
+        .into_iter()
+    }
+
+    fn enhance_pixel(&self, pixel: (isize, isize), algorithm: &HashSet<usize>) -> bool {
+        let binary_index = self
+            .neighbours(pixel)
+            .enumerate()
+            .filter(|(_, coordinate)| self.pixels_lit.contains(coordinate))
+            .map(|(index, _)| 2_u32.pow((9 - index - 1) as u32))
+            .sum::<u32>() as usize;
+
+        algorithm.contains(&binary_index)
+    }
+
+    pub fn enhance(&self, algorithm: &HashSet<usize>) -> Self {
+        let pixels_lit = self
+            .enlarged_x_range()
+            .flat_map(|x| self.enlarged_y_range().map(move |y| (x, y)))
+            .filter(|pixel| self.enhance_pixel(*pixel, algorithm))
+            .collect();
+
+        Self {
+            pixels_lit,
+            x_range: (self.x_range.0 - 2, self.x_range.1 + 2),
+            y_range: (self.y_range.0 - 2, self.y_range.1 + 2),
+        }
+    }
+
+    pub fn number_of_elements_lit(&self) -> usize {
+        self.pixels_lit
+            .iter()
+            .filter(|(x, y)| self.x_range().contains(x) && self.y_range().contains(y))
+            .count()
+    }
+}
+
+fn parse_pixel((span, pixel): (Span, char)) -> Result<bool, ParseError> {
+    match pixel {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(span.error("expected \"#\" or \".\"")),
+    }
+}
+
+fn parse_input(input: &str) -> Result<(HashSet<usize>, Image), ParseError> {
+    let (algorithm_input, image_input) = Span::new(input).split_once("\n\n")?;
+
+    let mut algorithm = HashSet::new();
+    for (index, pixel) in algorithm_input.chars().enumerate() {
+        if parse_pixel(pixel)? {
+            algorithm.insert(index);
+        }
+    }
+
+    let rows: Vec<Span> = image_input.lines().collect();
+    let size = (
+        rows.len() as isize,
+        rows.first().map_or(0, |row| row.as_str().len()) as isize,
+    );
+
+    let mut pixels_lit = HashSet::new();
+    for (y, row) in rows.iter().enumerate() {
+        for (x, pixel) in row.chars().enumerate() {
+            if parse_pixel(pixel)? {
+                pixels_lit.insert((x as isize, y as isize));
+            }
+        }
+    }
+
+    Ok((
+        algorithm,
+        Image {
+            pixels_lit,
+            x_range: (0, size.0),
+            y_range: (0, size.1),
+        },
+    ))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let (algorithm, image) = parse_input(input)?;
+
+        let enhanced_image = image.enhance(&algorithm).enhance(&algorithm);
+
+        Ok(enhanced_image.number_of_elements_lit().into())
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let (algorithm, image) = parse_input(input)?;
+        let mut image = image;
+
+        for _ in 0..50 {
+            image = image.enhance(&algorithm)
+        }
+
+        Ok(image.number_of_elements_lit().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_tests!(Day20, part_one = 35, part_two = 3351);
+}
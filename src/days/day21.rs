@@ -0,0 +1,165 @@
+use crate::parser::{self, ParseError, Span};
+use crate::solution::{Answer, Solution, SolveError};
+use std::cmp::{max, min};
+use std::collections::VecDeque;
+
+#[derive(Debug, Copy, Clone)]
+struct Player {
+    pub position: usize,
+    pub score: usize,
+}
+
+impl Player {
+    pub fn new(position: usize) -> Self {
+        Self { position, score: 0 }
+    }
+
+    pub fn step(&mut self, amount: usize) {
+        self.position += amount % 10;
+
+        if self.position > 10 {
+            self.position -= 10;
+        }
+
+        self.score += self.position;
+    }
+}
+
+struct DeterministicDice {
+    rolls: usize,
+    current: usize,
+}
+
+impl DeterministicDice {
+    fn new() -> Self {
+        Self {
+            rolls: 0,
+            current: 1,
+        }
+    }
+
+    fn roll(&mut self) -> usize {
+        self.rolls += 1;
+        let value = self.current;
+
+        self.current = match self.current {
+            100 => 1,
+            value => value + 1,
+        };
+
+        value
+    }
+}
+
+fn parse_player(line: Span) -> Result<Player, ParseError> {
+    let (_, position) = line.split_once(": ")?;
+    match position.parse()? {
+        start @ 1..=10 => Ok(Player::new(start)),
+        _ => Err(position.error("expected a position between 1 and 10")),
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Player, Player), ParseError> {
+    let players = parser::lines(input)
+        .map(parse_player)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match players[..] {
+        [player_1, player_2] => Ok((player_1, player_2)),
+        _ => Err(Span::new(input).trim().error("expected two players")),
+    }
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u8 = 21;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let (mut player_1, mut player_2) = parse_input(input)?;
+
+        let mut dice = DeterministicDice::new();
+        let mut game_ended = false;
+        let mut player_ones_turn = true;
+
+        while !game_ended {
+            let dice_result = dice.roll() + dice.roll() + dice.roll();
+
+            match player_ones_turn {
+                true => {
+                    player_1.step(dice_result);
+                    game_ended = player_1.score >= 1000;
+                }
+                false => {
+                    player_2.step(dice_result);
+                    game_ended = player_2.score >= 1000;
+                }
+            };
+
+            player_ones_turn = !player_ones_turn;
+        }
+
+        Ok(Answer::from(
+            min(player_1.score, player_2.score) * dice.rolls,
+        ))
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let (player_1, player_2) = parse_input(input)?;
+
+        let possible_outcomes = [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+        let mut wins: (usize, usize) = (0, 0);
+        let mut queue = VecDeque::new();
+        queue.push_front((player_1, player_2, true, 1));
+
+        while let Some((player_1, player_2, player_ones_turn, cases)) = queue.pop_front() {
+            if player_1.score >= 21 {
+                wins.0 += cases;
+                continue;
+            }
+
+            if player_2.score >= 21 {
+                wins.1 += cases;
+                continue;
+            }
+
+            for (dice_result, frequency) in possible_outcomes {
+                let mut player_1 = player_1;
+                let mut player_2 = player_2;
+
+                match player_ones_turn {
+                    true => player_1.step(dice_result),
+                    false => player_2.step(dice_result),
+                }
+
+                queue.push_front((player_1, player_2, !player_ones_turn, cases * frequency));
+            }
+        }
+
+        Ok(Answer::from(max(wins.0, wins.1)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_tests!(Day21, part_one = 739785, part_two = 444356092776315_u64);
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day21::part_one("Player 1 starting position: 4\nPlayer 2 starting position: 11")
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 2, column 29: expected a position between 1 and 10, found \"11\""
+        );
+        assert_eq!(
+            Day21::part_two("Player 1 starting position: 4\n")
+                .unwrap_err()
+                .to_string(),
+            "parse error at line 1, column 1: expected two players, found \"Player 1 starting position: 4\""
+        );
+    }
+}
@@ -0,0 +1,332 @@
+use crate::parser::{self, ParseError};
+use crate::solution::{Answer, Solution, SolveError};
+extern crate core;
+
+use itertools::Itertools;
+use std::cmp::min;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+type Coordinate = (usize, usize);
+
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub enum Amphipod {
+    Amber = 0,
+    Bronze = 1,
+    Copper = 2,
+    Desert = 3,
+}
+
+#[derive(Debug, Clone, Copy, Hash, Eq, PartialEq)]
+pub enum LocationType {
+    Room(Amphipod),
+    Hallway,
+}
+
+struct Grid {
+    pub rooms: Vec<Vec<Coordinate>>,
+    grid: HashMap<Coordinate, LocationType>,
+}
+
+impl Grid {
+    pub fn new(locations: HashMap<Coordinate, LocationType>) -> Self {
+        let rooms = [
+            Amphipod::Amber,
+            Amphipod::Bronze,
+            Amphipod::Copper,
+            Amphipod::Desert,
+        ]
+        .into_iter()
+        .map(|amphipod| {
+            locations
+                .iter()
+                .filter(|(_, location)| {
+                    if LocationType::Room(amphipod) == **location {
+                        return true;
+                    }
+
+                    false
+                })
+                .map(|(coordinate, _)| *coordinate)
+                .collect()
+        })
+        .collect::<Vec<Vec<Coordinate>>>();
+
+        Self {
+            rooms,
+            grid: locations,
+        }
+    }
+
+    fn location(&self, coordinate: &Coordinate) -> LocationType {
+        *self.grid.get(coordinate).unwrap()
+    }
+
+    fn neighbours(&self, coordinate: &Coordinate) -> Vec<Coordinate> {
+        let (i, j) = *coordinate;
+
+        vec![(i + 1, j), (i - 1, j), (i, j + 1), (i, j - 1)]
+            .into_iter()
+            .filter(|coordinate| self.grid.contains_key(coordinate))
+            .collect()
+    }
+
+    fn can_stop_at(&self, coordinate: &Coordinate) -> bool {
+        match self.grid.get(coordinate).unwrap() {
+            LocationType::Room(_) => true,
+            LocationType::Hallway => self.neighbours(coordinate).iter().all(|coordinate| {
+                !matches!(self.grid.get(coordinate).unwrap(), LocationType::Room(_))
+            }),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<(Vec<(Coordinate, Amphipod)>, Grid), ParseError> {
+    let mut locations = HashMap::new();
+    let mut amphipods = vec![];
+
+    for (y, line) in parser::lines(input).enumerate() {
+        let mut number_of_rooms = 0;
+
+        for (x, (span, element)) in line.chars().enumerate() {
+            let amphipod = match element {
+                '#' | ' ' => continue,
+                '.' => {
+                    locations.insert((x, y), LocationType::Hallway);
+                    continue;
+                }
+                'A' => Amphipod::Amber,
+                'B' => Amphipod::Bronze,
+                'C' => Amphipod::Copper,
+                'D' => Amphipod::Desert,
+                _ => return Err(span.error("expected \"#\", \".\" or an amphipod")),
+            };
+
+            let room = match number_of_rooms {
+                0 => Amphipod::Amber,
+                1 => Amphipod::Bronze,
+                2 => Amphipod::Copper,
+                3 => Amphipod::Desert,
+                _ => return Err(span.error("expected four rooms per row")),
+            };
+            number_of_rooms += 1;
+
+            locations.insert((x, y), LocationType::Room(room));
+            amphipods.push(((x, y), amphipod));
+        }
+    }
+
+    Ok((amphipods, Grid::new(locations)))
+}
+
+fn solve(
+    amphipods: Vec<(Coordinate, Amphipod)>,
+    grid: &Grid,
+    number_of_amphipods_per_specie: usize,
+) -> usize {
+    let mut queue = VecDeque::new();
+    queue.push_front((amphipods, 0, vec![]));
+
+    let coordinate_per_room_ordered_by_depth: Vec<Vec<Coordinate>> = grid
+        .rooms
+        .iter()
+        .map(|room| {
+            room.iter()
+                .cloned()
+                .sorted_by_key(|coordinate| coordinate.1)
+                .rev()
+                .collect()
+        })
+        .collect();
+
+    let mut visited_states = HashMap::<Vec<(Coordinate, Amphipod)>, usize>::new();
+    let mut lowest_cost = usize::MAX;
+
+    while let Some((amphipods, cost, cost_per_move)) = queue.pop_front() {
+        if cost >= lowest_cost {
+            continue;
+        }
+
+        if let Some(previous_cost) = visited_states.get(&amphipods) {
+            if *previous_cost <= cost {
+                continue;
+            }
+        }
+
+        let room_occupation: Vec<HashSet<Coordinate>> = grid
+            .rooms
+            .iter()
+            .enumerate()
+            .map(|(index, coordinates)| {
+                amphipods
+                    .iter()
+                    .filter(|(_, amphipod)| *amphipod as usize == index)
+                    .filter(|(current, _)| coordinates.contains(current))
+                    .map(|(coordinate, _)| *coordinate)
+                    .collect()
+            })
+            .collect();
+
+        if room_occupation.iter().all(|occupied_coordinates| {
+            occupied_coordinates.len() == number_of_amphipods_per_specie
+        }) {
+            lowest_cost = min(lowest_cost, cost);
+            continue;
+        }
+
+        visited_states.insert(amphipods.clone(), cost);
+
+        for (index, (coordinate, amphipod)) in amphipods.iter().enumerate() {
+            // No need to do anything with this amphipod if the target room is completely filled with
+            //  amphipods of the correct type.
+            let target_room_occupation = &room_occupation[*amphipod as usize];
+
+            if target_room_occupation.len() == number_of_amphipods_per_specie {
+                continue;
+            }
+
+            let room_coordinates_ordered_by_depth =
+                &coordinate_per_room_ordered_by_depth[*amphipod as usize];
+
+            if !target_room_occupation.is_empty() {
+                let current_in_stack = room_coordinates_ordered_by_depth
+                    .iter()
+                    .take(target_room_occupation.len())
+                    .any(|target| target == coordinate);
+
+                let stack_completely_filled = room_coordinates_ordered_by_depth
+                    .iter()
+                    .take(target_room_occupation.len())
+                    .all(|coordinate| target_room_occupation.contains(coordinate));
+
+                if current_in_stack && stack_completely_filled {
+                    continue;
+                }
+            }
+
+            let occupied_positions: HashSet<Coordinate> = amphipods
+                .iter()
+                .map(|(coordinate, _)| *coordinate)
+                .collect();
+
+            let mut visited_positions = HashSet::<(usize, usize)>::new();
+            let mut position_queue = VecDeque::<((usize, usize), usize)>::new();
+
+            position_queue.push_front((*coordinate, 0));
+
+            let Some(target_room_coordinate) = room_coordinates_ordered_by_depth
+                .iter()
+                .find(|coordinate| !target_room_occupation.contains(coordinate))
+            else {
+                continue;
+            };
+
+            while let Some((next_coordinate, steps)) = position_queue.pop_front() {
+                // If this coordinate has been explored already, the search stops.
+                if visited_positions.contains(&next_coordinate) {
+                    continue;
+                }
+
+                // Add all the non-explored neighbours of the current coordinate to the search.
+                position_queue.extend(
+                    grid.neighbours(&next_coordinate)
+                        .iter()
+                        .filter(|coordinate| !occupied_positions.contains(coordinate))
+                        .filter(|coordinate| !visited_positions.contains(coordinate))
+                        .map(|coordinate| (*coordinate, steps + 1)),
+                );
+
+                visited_positions.insert(next_coordinate);
+
+                // Make sure that an Amphipod moves once out of a room, and once into a room.
+                match (grid.location(coordinate), grid.location(&next_coordinate)) {
+                    (_, LocationType::Room(_)) => {
+                        if next_coordinate != *target_room_coordinate {
+                            continue;
+                        }
+                    }
+                    (LocationType::Room(_), LocationType::Hallway) => {
+                        if !grid.can_stop_at(&next_coordinate) {
+                            continue;
+                        }
+                    }
+                    _ => continue,
+                }
+
+                // Add the new state to the search space.
+                let mut amphipods = amphipods.clone();
+                amphipods[index].0 = next_coordinate;
+
+                let cost_per_step = match amphipod {
+                    Amphipod::Amber => 1,
+                    Amphipod::Bronze => 10,
+                    Amphipod::Copper => 100,
+                    Amphipod::Desert => 1000,
+                };
+
+                let mut cost_per_move = cost_per_move.clone();
+                cost_per_move.push(steps * cost_per_step);
+
+                let next_state = (amphipods, cost + steps * cost_per_step, cost_per_move);
+                queue.push_front(next_state)
+            }
+        }
+    }
+
+    lowest_cost
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u8 = 23;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let (amphipods, grid) = parse_input(input)?;
+        let number_of_amphipods_per_specie = 2;
+
+        Ok(Answer::from(solve(
+            amphipods,
+            &grid,
+            number_of_amphipods_per_specie,
+        )))
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        // report errors at their line in the input, before the folded lines are inserted.
+        parse_input(input)?;
+
+        let mut input = input.lines().collect::<Vec<_>>();
+        input.insert(3, "  #D#C#B#A#");
+        input.insert(4, "  #D#B#A#C#");
+
+        let input = input.join("\n");
+
+        let (amphipods, grid) = parse_input(&input)?;
+        let number_of_amphipods_per_specie = 4;
+
+        Ok(Answer::from(solve(
+            amphipods,
+            &grid,
+            number_of_amphipods_per_specie,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_tests!(Day23, part_one = 12521, part_two = 44169);
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day23::part_two(
+                "#############\n#...........#\n###B#C#B#E###\n  #A#D#C#A#\n  #########"
+            )
+            .unwrap_err()
+            .to_string(),
+            "parse error at line 3, column 10: expected \"#\", \".\" or an amphipod, found \"E\""
+        );
+    }
+}
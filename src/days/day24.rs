@@ -0,0 +1,340 @@
+use crate::parser::{self, ParseError, Span};
+use crate::solution::{Answer, Solution, SolveError};
+use std::collections::HashSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Hash, Eq)]
+pub enum Variable {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Placeholder {
+    Variable(Variable),
+    Number(isize),
+    ParsedInput(isize),
+    Input,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InstructionType {
+    Inp,
+    Add,
+    Mul,
+    Div,
+    Mod,
+    Eql,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Instruction {
+    pub instruction_type: InstructionType,
+    pub variable: Variable,
+    pub placeholder: Placeholder,
+}
+
+impl Placeholder {
+    pub fn new(data: Span) -> Result<Self, ParseError> {
+        match data.as_str() {
+            "w" => Ok(Placeholder::Variable(Variable::W)),
+            "x" => Ok(Placeholder::Variable(Variable::X)),
+            "y" => Ok(Placeholder::Variable(Variable::Y)),
+            "z" => Ok(Placeholder::Variable(Variable::Z)),
+            _ => Ok(Placeholder::Number(data.parse()?)),
+        }
+    }
+}
+
+fn parse_input(input: &str) -> Result<Vec<Instruction>, ParseError> {
+    parser::lines(input)
+        .map(|line| {
+            let mut data = line.split_whitespace();
+            let instruction = data.next().unwrap_or(line);
+            let instruction_type = match instruction.as_str() {
+                "inp" => InstructionType::Inp,
+                "add" => InstructionType::Add,
+                "mul" => InstructionType::Mul,
+                "div" => InstructionType::Div,
+                "mod" => InstructionType::Mod,
+                "eql" => InstructionType::Eql,
+                _ => return Err(instruction.error("expected an instruction")),
+            };
+
+            let variable = data.next().unwrap_or(line);
+            let variable = match Placeholder::new(variable) {
+                Ok(Placeholder::Variable(variable)) => variable,
+                _ => return Err(variable.error("expected a variable")),
+            };
+
+            let placeholder = match (instruction_type, data.next()) {
+                (InstructionType::Inp, None) => Placeholder::Input,
+                (InstructionType::Inp, Some(data)) => {
+                    return Err(data.error("expected the end of the line"))
+                }
+                (_, Some(data)) => Placeholder::new(data)?,
+                (_, None) => return Err(line.error("expected a variable or a number")),
+            };
+
+            Ok(Instruction {
+                instruction_type,
+                variable,
+                placeholder,
+            })
+        })
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ArithmeticLogicUnitInput {
+    input: Vec<isize>,
+    index: usize,
+}
+
+impl ArithmeticLogicUnitInput {
+    pub fn new(input: Vec<isize>) -> Self {
+        Self { input, index: 0 }
+    }
+
+    pub fn read(&mut self) -> isize {
+        let result = self.input[self.index];
+        self.index += 1;
+        result
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArithmeticLogicUnit {
+    registers: [isize; 4],
+}
+
+impl Default for ArithmeticLogicUnit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArithmeticLogicUnit {
+    pub fn new() -> Self {
+        Self {
+            registers: [0, 0, 0, 0],
+        }
+    }
+
+    fn variable_index(&self, variable: Variable) -> usize {
+        match variable {
+            Variable::W => 0,
+            Variable::X => 1,
+            Variable::Y => 2,
+            Variable::Z => 3,
+        }
+    }
+
+    pub fn get_variable_value(&self, variable: Variable) -> isize {
+        self.registers[self.variable_index(variable)]
+    }
+
+    fn set_variable_value(&mut self, variable: Variable, value: isize) {
+        self.registers[self.variable_index(variable)] = value;
+    }
+
+    /// `None` for an input that has not been read yet.
+    fn get_placeholder_value(&mut self, placeholder: Placeholder) -> Option<isize> {
+        match placeholder {
+            Placeholder::Variable(variable) => Some(self.get_variable_value(variable)),
+            Placeholder::Number(number) => Some(number),
+            Placeholder::ParsedInput(number) => Some(number),
+            Placeholder::Input => None,
+        }
+    }
+
+    pub fn apply_all(&mut self, instructions: &[Instruction]) -> Option<()> {
+        for instruction in instructions {
+            self.apply(instruction)?;
+        }
+
+        Some(())
+    }
+
+    /// Returns `None` if the instruction crashes the ALU, e.g. a division by zero.
+    pub fn apply(&mut self, instruction: &Instruction) -> Option<()> {
+        let variable = instruction.variable;
+        let variable_value = self.get_variable_value(variable);
+        let value = self.get_placeholder_value(instruction.placeholder)?;
+
+        let result = match instruction.instruction_type {
+            InstructionType::Inp => value,
+            InstructionType::Add => variable_value + value,
+            InstructionType::Mul => variable_value * value,
+            InstructionType::Div => variable_value.checked_div(value)?,
+            InstructionType::Mod => {
+                if value <= 0 || variable_value < 0 {
+                    return None;
+                }
+                variable_value % value
+            }
+            InstructionType::Eql => (variable_value == value) as isize,
+        };
+
+        self.set_variable_value(variable, result);
+        Some(())
+    }
+}
+
+fn construct_model_number(
+    alu: ArithmeticLogicUnit,
+    instructions: &[Instruction],
+    instruction_index: usize,
+    input: [isize; 14],
+    input_index: usize,
+    possible_numbers: &[isize],
+    infeasible_states: &mut HashSet<(usize, isize)>,
+) -> Option<usize> {
+    if input_index == 14 {
+        return match alu.get_variable_value(Variable::Z) {
+            0 => Some(
+                input
+                    .iter()
+                    .fold(0, |number, digit| number * 10 + *digit as usize),
+            ),
+            _ => None,
+        };
+    }
+
+    let state_key = (input_index, alu.get_variable_value(Variable::Z));
+    if infeasible_states.contains(&state_key) {
+        return None;
+    }
+
+    for number in possible_numbers {
+        let mut alu = alu;
+        let mut instruction_index = instruction_index + 1;
+
+        let mut crashed = alu
+            .apply(&Instruction {
+                instruction_type: InstructionType::Inp,
+                variable: Variable::W,
+                placeholder: Placeholder::ParsedInput(*number),
+            })
+            .is_none();
+
+        while let Some(instruction) = instructions.get(instruction_index) {
+            if crashed || instruction.instruction_type == InstructionType::Inp {
+                break;
+            }
+
+            crashed = alu.apply(instruction).is_none();
+            instruction_index += 1;
+        }
+
+        if crashed {
+            continue;
+        }
+
+        let mut input = input;
+        input[input_index] = *number;
+
+        let result = construct_model_number(
+            alu,
+            instructions,
+            instruction_index,
+            input,
+            input_index + 1,
+            possible_numbers,
+            infeasible_states,
+        );
+
+        if result.is_some() {
+            return result;
+        }
+
+        infeasible_states.insert(state_key);
+    }
+
+    None
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let instructions = parse_input(input)?;
+
+        let mut infeasible_states: HashSet<(usize, isize)> = HashSet::new();
+        construct_model_number(
+            ArithmeticLogicUnit::new(),
+            &instructions,
+            0,
+            [0; 14],
+            0,
+            &[9, 8, 7, 6, 5, 4, 3, 2, 1],
+            &mut infeasible_states,
+        )
+        .map(Answer::from)
+        .ok_or_else(|| SolveError::unsolvable("no valid model number"))
+    }
+
+    fn part_two(input: &str) -> Result<Answer, SolveError> {
+        let instructions = parse_input(input)?;
+
+        let mut infeasible_states: HashSet<(usize, isize)> = HashSet::new();
+        construct_model_number(
+            ArithmeticLogicUnit::new(),
+            &instructions,
+            0,
+            [0; 14],
+            0,
+            &[1, 2, 3, 4, 5, 6, 7, 8, 9],
+            &mut infeasible_states,
+        )
+        .map(Answer::from)
+        .ok_or_else(|| SolveError::unsolvable("no valid model number"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_model_number() {
+        let program = "inp w\n".repeat(14);
+        assert_eq!(
+            Day24::part_one(&program),
+            Ok(Answer::from(99999999999999_u64))
+        );
+        assert_eq!(
+            Day24::part_two(&program),
+            Ok(Answer::from(11111111111111_u64))
+        );
+
+        let program = program + "div z 0";
+        assert_eq!(
+            Day24::part_one(&program).unwrap_err().to_string(),
+            "unsolvable input: no valid model number"
+        );
+    }
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day24::part_one("inp w\nsub x 1").unwrap_err().to_string(),
+            "parse error at line 2, column 1: expected an instruction, found \"sub\""
+        );
+        assert_eq!(
+            Day24::part_one("inp q").unwrap_err().to_string(),
+            "parse error at line 1, column 5: expected a variable, found \"q\""
+        );
+        assert_eq!(
+            Day24::part_two("inp w\nadd x").unwrap_err().to_string(),
+            "parse error at line 2, column 1: expected a variable or a number, found \"add x\""
+        );
+        assert_eq!(
+            Day24::part_two("inp w\nmul x 2a").unwrap_err().to_string(),
+            "parse error at line 2, column 7: invalid digit found in string, found \"2a\""
+        );
+    }
+}
@@ -0,0 +1,173 @@
+use crate::parser::{self, ParseError};
+use crate::solution::{Answer, Solution, SolveError};
+use std::collections::HashSet;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+struct Coordinate {
+    x: usize,
+    y: usize,
+}
+
+impl Coordinate {
+    fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    fn to_east(self, grid_size: &(usize, usize)) -> Self {
+        let mut next_x = self.x + 1;
+        if next_x > grid_size.0 {
+            next_x = 0;
+        }
+
+        Self {
+            x: next_x,
+            y: self.y,
+        }
+    }
+
+    fn to_south(self, grid_size: &(usize, usize)) -> Self {
+        let mut next_y = self.y + 1;
+        if next_y > grid_size.1 {
+            next_y = 0;
+        }
+
+        Self {
+            x: self.x,
+            y: next_y,
+        }
+    }
+}
+
+struct Grid {
+    pub grid_size: (usize, usize),
+    pub east_facing_cucumbers: HashSet<Coordinate>,
+    pub south_facing_cucumbers: HashSet<Coordinate>,
+}
+
+impl Grid {
+    fn new(
+        east_facing_cucumbers: HashSet<Coordinate>,
+        south_facing_cucumbers: HashSet<Coordinate>,
+    ) -> Self {
+        let mut all_points = east_facing_cucumbers.clone();
+        all_points.extend(&south_facing_cucumbers);
+
+        let max_x = all_points
+            .iter()
+            .map(|coord| coord.x)
+            .max()
+            .unwrap_or_default();
+        let max_y = all_points
+            .iter()
+            .map(|coord| coord.y)
+            .max()
+            .unwrap_or_default();
+
+        Self {
+            grid_size: (max_x, max_y),
+            east_facing_cucumbers,
+            south_facing_cucumbers,
+        }
+    }
+
+    fn contains_cucumber_at(&self, target: Coordinate) -> bool {
+        self.east_facing_cucumbers.contains(&target)
+            || self.south_facing_cucumbers.contains(&target)
+    }
+
+    fn simulate(&mut self) -> bool {
+        let mut has_changed = false;
+        let mut east_facing_cucumbers_changes: Vec<(Coordinate, Coordinate)> = Vec::new();
+        let mut south_facing_cucumbers_changes: Vec<(Coordinate, Coordinate)> = Vec::new();
+
+        for origin in &self.east_facing_cucumbers {
+            let target = origin.to_east(&self.grid_size);
+
+            if self.contains_cucumber_at(target) {
+                continue;
+            }
+
+            east_facing_cucumbers_changes.push((*origin, target))
+        }
+
+        has_changed = has_changed || !east_facing_cucumbers_changes.is_empty();
+
+        for (origin, target) in east_facing_cucumbers_changes {
+            self.east_facing_cucumbers.remove(&origin);
+            self.east_facing_cucumbers.insert(target);
+        }
+
+        for origin in &self.south_facing_cucumbers {
+            let target = origin.to_south(&self.grid_size);
+
+            if self.contains_cucumber_at(target) {
+                continue;
+            }
+
+            south_facing_cucumbers_changes.push((*origin, target))
+        }
+
+        has_changed = has_changed || !south_facing_cucumbers_changes.is_empty();
+
+        for (origin, target) in south_facing_cucumbers_changes {
+            self.south_facing_cucumbers.remove(&origin);
+            self.south_facing_cucumbers.insert(target);
+        }
+
+        has_changed
+    }
+}
+
+fn parse_input(input: &str) -> Result<Grid, ParseError> {
+    let mut east_facing_cucumbers = HashSet::default();
+    let mut south_facing_cucumbers = HashSet::default();
+
+    for (y, line) in parser::lines(input).enumerate() {
+        for (x, (span, element)) in line.chars().enumerate() {
+            match element {
+                '.' => {}
+                '>' => {
+                    east_facing_cucumbers.insert(Coordinate::new(x, y));
+                }
+                'v' => {
+                    south_facing_cucumbers.insert(Coordinate::new(x, y));
+                }
+                _ => return Err(span.error("expected \".\", \">\" or \"v\"")),
+            }
+        }
+    }
+
+    Ok(Grid::new(east_facing_cucumbers, south_facing_cucumbers))
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    fn part_one(input: &str) -> Result<Answer, SolveError> {
+        let mut grid = parse_input(input)?;
+        let mut iterations = 1;
+
+        while grid.simulate() {
+            iterations += 1;
+        }
+
+        Ok(iterations.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    crate::aoc_tests!(Day25, part_one = 58);
+
+    #[test]
+    fn test_parse_error() {
+        assert_eq!(
+            Day25::part_one("v...>\n.<..v").unwrap_err().to_string(),
+            "parse error at line 2, column 2: expected \".\", \">\" or \"v\", found \"<\""
+        );
+    }
+}
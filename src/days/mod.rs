@@ -0,0 +1,122 @@
+/*
+ * Solutions are registered here so they can be run in-process by `cargo all`.
+ * `cargo scaffold` adds a `pub mod` line and a `day!` entry to `DAYS` for a new day.
+ * Other years live in `yYYYY` modules with their own `DAYS`, which `cargo scaffold --year`
+ * registers in `YEARS`.
+ */
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day18;
+pub mod day20;
+pub mod day21;
+pub mod day22;
+pub mod day23;
+pub mod day24;
+pub mod day25;
+
+use crate::solution::{Answer, Solution, SolveError};
+
+/// A solver for one part of a puzzle.
+pub type Solver = fn(&str) -> Result<Answer, SolveError>;
+
+/// A registered day with its callable solution parts.
+pub struct Day {
+    pub day: u8,
+    pub part_one: Solver,
+    pub part_two: Option<Solver>,
+    /// Parts whose answer is read by hand, see [`Solution::MANUAL`].
+    pub manual: &'static [u8],
+}
+
+macro_rules! day {
+    ($solution:ty) => {
+        Day {
+            day: <$solution>::DAY,
+            part_one: <$solution>::part_one,
+            part_two: Some(<$solution>::part_two),
+            manual: <$solution>::MANUAL,
+        }
+    };
+    ($solution:ty, part_one) => {
+        Day {
+            day: <$solution>::DAY,
+            part_one: <$solution>::part_one,
+            part_two: None,
+            manual: <$solution>::MANUAL,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(day01::Day01),
+    day!(day02::Day02),
+    day!(day03::Day03),
+    day!(day04::Day04),
+    day!(day05::Day05),
+    day!(day06::Day06),
+    day!(day07::Day07),
+    day!(day08::Day08),
+    day!(day09::Day09),
+    day!(day10::Day10),
+    day!(day11::Day11),
+    day!(day12::Day12),
+    day!(day13::Day13),
+    day!(day14::Day14),
+    day!(day15::Day15),
+    day!(day16::Day16),
+    day!(day17::Day17),
+    day!(day18::Day18),
+    day!(day20::Day20),
+    day!(day21::Day21),
+    day!(day22::Day22),
+    day!(day23::Day23),
+    day!(day24::Day24),
+    day!(day25::Day25, part_one),
+];
+
+/// The year of the solutions in `DAYS`.
+pub const YEAR: u16 = 2021;
+
+/// The registered solutions of every year, run with `--year`, see [`crate::workspace`].
+/// Year modules are declared after the `day!` macro so they can use it.
+pub const YEARS: &[(u16, &[Day])] = &[(YEAR, DAYS)];
+
+/// The solutions registered for `year`, or `DAYS` if no year is selected.
+pub fn registered(year: Option<u16>) -> Option<&'static [Day]> {
+    match year {
+        Some(year) => YEARS
+            .iter()
+            .find(|(registered, _)| *registered == year)
+            .map(|(_, days)| *days),
+        None => Some(DAYS),
+    }
+}
+
+impl Day {
+    /// Returns the registered parts together with their part number.
+    pub fn parts(&self) -> impl Iterator<Item = (u8, Solver)> {
+        [Some(self.part_one), self.part_two]
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, solver)| solver.map(|solver| (index as u8 + 1, solver)))
+    }
+
+    pub fn is_manual(&self, part: u8) -> bool {
+        self.manual.contains(&part)
+    }
+}
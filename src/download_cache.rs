@@ -0,0 +1,133 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::workspace::Workspace;
+use std::collections::BTreeMap;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The state of a downloaded file in `src/inputs` or `src/puzzles`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheState {
+    /// The file does not exist or is empty.
+    Missing,
+    /// The file matches the checksum recorded when it was downloaded.
+    Unchanged,
+    /// The file was edited since it was downloaded.
+    Modified,
+    /// The file exists, but was not downloaded by `cargo download`.
+    Untracked,
+}
+
+/// Checksums of downloaded inputs, stored in `src/inputs/.checksums` as `day<TAB>checksum`
+/// lines, used to detect inputs that were edited by accident.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Checksums {
+    checksums: BTreeMap<u8, u64>,
+}
+
+impl Checksums {
+    pub fn path() -> PathBuf {
+        Workspace::current().dir("inputs").join(".checksums")
+    }
+
+    /// Loads the checksums. A missing file yields no checksums.
+    pub fn load() -> io::Result<Self> {
+        match fs::read_to_string(Self::path()) {
+            Ok(contents) => Ok(Self::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        fs::write(Self::path(), self.to_string())
+    }
+
+    /// Parses checksum lines, skipping malformed ones.
+    pub fn parse(contents: &str) -> Self {
+        let checksums = contents
+            .lines()
+            .filter_map(|line| {
+                let (day, checksum) = line.split_once('\t')?;
+                Some((
+                    day.trim().parse().ok()?,
+                    u64::from_str_radix(checksum.trim(), 16).ok()?,
+                ))
+            })
+            .collect();
+
+        Self { checksums }
+    }
+
+    pub fn insert(&mut self, day: u8, contents: &str) {
+        self.checksums.insert(day, checksum(contents));
+    }
+
+    /// Compares the file at `path` to the checksum recorded for `day`.
+    pub fn state(&self, day: u8, path: &Path) -> CacheState {
+        match fs::read_to_string(path) {
+            Ok(contents) if !contents.is_empty() => match self.checksums.get(&day) {
+                Some(&expected) if expected == checksum(&contents) => CacheState::Unchanged,
+                Some(_) => CacheState::Modified,
+                None => CacheState::Untracked,
+            },
+            _ => CacheState::Missing,
+        }
+    }
+}
+
+impl Display for Checksums {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (day, checksum) in &self.checksums {
+            writeln!(f, "{day:02}\t{checksum:016x}")?;
+        }
+        Ok(())
+    }
+}
+
+/// A 64-bit FNV-1a hash, which is stable across Rust versions unlike `DefaultHasher`.
+pub fn checksum(contents: &str) -> u64 {
+    contents.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checksum() {
+        assert_eq!(checksum(""), 0xcbf29ce484222325);
+        assert_eq!(checksum("a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(checksum("199\n200\n"), checksum("199\n201\n"));
+    }
+
+    #[test]
+    fn test_state() {
+        let mut checksums = Checksums::parse("01\tff\ninvalid\n");
+        checksums.insert(3, &fs::read_to_string("src/examples/03.txt").unwrap());
+        assert_eq!(Checksums::parse(&checksums.to_string()), checksums);
+
+        assert_eq!(
+            checksums.state(3, Path::new("src/examples/03.txt")),
+            CacheState::Unchanged
+        );
+        assert_eq!(
+            checksums.state(1, Path::new("src/examples/01.txt")),
+            CacheState::Modified
+        );
+        assert_eq!(
+            checksums.state(2, Path::new("src/examples/02.txt")),
+            CacheState::Untracked
+        );
+        assert_eq!(
+            checksums.state(1, Path::new("src/examples/missing.txt")),
+            CacheState::Missing
+        );
+    }
+}
@@ -0,0 +1,182 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::Answers;
+use crate::days::Solver;
+use crate::solution::Solution;
+use crate::workspace::Workspace;
+use std::ffi::OsStr;
+use std::fs;
+use std::io;
+
+/// A worked example of a puzzle, stored as `src/examples/NN/<name>.txt` with its expected
+/// answers in `<name>.answers`, one line per part like the answers store.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    pub answers: Answers,
+}
+
+impl Example {
+    /// Loads the examples of `day`, sorted by name. An example without an answers file
+    /// has no expected answers.
+    pub fn load(workspace: Workspace, day: u8) -> io::Result<Vec<Example>> {
+        let mut examples = vec![];
+
+        for entry in fs::read_dir(workspace.dir("examples").join(format!("{day:02}")))? {
+            let path = entry?.path();
+            if path.extension() != Some(OsStr::new("txt")) {
+                continue;
+            }
+
+            let answers = match fs::read_to_string(path.with_extension("answers")) {
+                Ok(contents) => Answers::parse(&contents),
+                Err(e) if e.kind() == io::ErrorKind::NotFound => Answers::default(),
+                Err(e) => return Err(e),
+            };
+
+            examples.push(Example {
+                name: path
+                    .file_stem()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .into_owned(),
+                input: fs::read_to_string(&path)?,
+                answers,
+            });
+        }
+
+        examples.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(examples)
+    }
+}
+
+/// Reads the example `src/examples/NN.txt` of a solution, from its year's folder if it has one.
+pub fn read_example<S: Solution>() -> String {
+    match S::YEAR {
+        Some(year) => crate::read_year_file(year, "examples", S::DAY),
+        None => crate::read_file("examples", S::DAY),
+    }
+}
+
+/// Runs `part` of a solution against all of its examples with an expected answer and panics
+/// with the examples that failed, e.g. `assert_examples::<Day12>(1)` in a test.
+#[track_caller]
+pub fn assert_examples<S: Solution>(part: u8) {
+    let workspace = match S::YEAR {
+        Some(year) => Workspace::new(Some(year)),
+        None => Workspace::current(),
+    };
+    let examples = Example::load(workspace, S::DAY)
+        .unwrap_or_else(|e| panic!("could not read the examples of day {}: {e}", S::DAY));
+    let solver: Solver = match part {
+        1 => S::part_one,
+        _ => S::part_two,
+    };
+
+    if let Err(failures) = check_examples(&examples, part, solver) {
+        panic!("{failures}");
+    }
+}
+
+/// Runs `solver` against the examples with an expected answer to `part`. Returns how many
+/// were checked, or a description of the ones that failed.
+pub fn check_examples(examples: &[Example], part: u8, solver: Solver) -> Result<usize, String> {
+    let mut checked = 0;
+    let mut failures = vec![];
+
+    for example in examples {
+        let Some(expected) = example.answers.get(part) else {
+            continue;
+        };
+
+        checked += 1;
+        match solver(&example.input) {
+            Ok(answer) if answer.to_string() == expected => {}
+            Ok(answer) => failures.push(format!(
+                "  {}: expected {expected}, got {answer}",
+                example.name
+            )),
+            Err(error) => failures.push(format!(
+                "  {}: expected {expected}, got {error}",
+                example.name
+            )),
+        }
+    }
+
+    match (checked, failures.len()) {
+        (0, _) => Err(format!("no example has an expected answer for part {part}")),
+        (_, 0) => Ok(checked),
+        (_, failed) => Err(format!(
+            "{failed} of {checked} examples failed for part {part}:\n{}",
+            failures.join("\n")
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solution::{Answer, SolveError};
+
+    fn example(name: &str, input: &str, answers: &str) -> Example {
+        Example {
+            name: name.into(),
+            input: input.into(),
+            answers: Answers::parse(answers),
+        }
+    }
+
+    fn line_count(input: &str) -> Result<Answer, SolveError> {
+        match input.lines().count() {
+            0 => Err(SolveError::unsolvable("empty input")),
+            count => Ok(count.into()),
+        }
+    }
+
+    #[test]
+    fn test_check_examples() {
+        let examples = [
+            example("one", "a", "1\n"),
+            example("two", "a\nb", "3\n"),
+            example("empty", "", "0\n"),
+            example("unknown", "a", "\n1"),
+        ];
+
+        assert_eq!(check_examples(&examples[..1], 1, line_count), Ok(1));
+        assert_eq!(
+            check_examples(&examples, 1, line_count),
+            Err("2 of 3 examples failed for part 1:\n  two: expected 3, got 2\n  empty: expected 0, got unsolvable input: empty input".into())
+        );
+        assert_eq!(
+            check_examples(&examples[..3], 2, line_count),
+            Err("no example has an expected answer for part 2".into())
+        );
+    }
+
+    struct LineCount;
+
+    impl Solution for LineCount {
+        const DAY: u8 = 1;
+
+        fn part_one(input: &str) -> Result<Answer, SolveError> {
+            line_count(input)
+        }
+
+        fn part_two(input: &str) -> Result<Answer, SolveError> {
+            line_count(input)
+        }
+    }
+
+    crate::aoc_tests!(LineCount, part_one = ["a\nb" => 2, "a" => 1], part_two = manual);
+
+    #[test]
+    fn test_load() {
+        let examples = Example::load(Workspace::new(None), 12).unwrap();
+        let names: Vec<&str> = examples.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, ["larger", "largest", "small"]);
+        assert_eq!(examples[2].answers.get(1), Some("10"));
+    }
+}
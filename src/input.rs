@@ -0,0 +1,144 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::try_read_file;
+use crate::workspace::Workspace;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+const DEFAULT_FOLDER: &str = "inputs";
+
+/// Where the puzzle input of a day is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `src/<folder>/NN.txt` of the selected year, `src/inputs` by default.
+    Folder(String),
+    /// A single input file, or a directory containing `NN.txt` files.
+    Path(PathBuf),
+    /// Standard input, read once.
+    Stdin,
+    /// An input given as a string, e.g. in tests.
+    Inline(String),
+}
+
+impl Default for InputSource {
+    fn default() -> Self {
+        InputSource::Folder(DEFAULT_FOLDER.into())
+    }
+}
+
+impl Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Folder(folder) => write!(f, "{:?}", Workspace::current().dir(folder)),
+            InputSource::Path(path) => write!(f, "{path:?}"),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Inline(_) => write!(f, "inline input"),
+        }
+    }
+}
+
+impl InputSource {
+    /// Parses `--input <path>` (`-` for stdin) and `--input-folder <folder>`.
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let path: Option<String> = args.opt_value_from_str("--input")?;
+        let folder: Option<String> = args.opt_value_from_str("--input-folder")?;
+
+        match (path, folder) {
+            (Some(_), Some(_)) => Err(pico_args::Error::ArgumentParsingFailed {
+                cause: "--input and --input-folder can't be combined".into(),
+            }),
+            (Some(path), None) if path == "-" => Ok(InputSource::Stdin),
+            (Some(path), None) => Ok(InputSource::Path(path.into())),
+            (None, Some(folder)) => Ok(InputSource::Folder(folder)),
+            (None, None) => Ok(Self::default()),
+        }
+    }
+
+    pub fn read(&self, day: u8) -> io::Result<String> {
+        match self {
+            InputSource::Folder(folder) => try_read_file(folder, day),
+            InputSource::Path(path) if path.is_dir() => {
+                fs::read_to_string(path.join(format!("{day:02}.txt")))
+            }
+            InputSource::Path(path) => fs::read_to_string(path),
+            InputSource::Stdin => io::read_to_string(io::stdin()),
+            InputSource::Inline(input) => Ok(input.clone()),
+        }
+    }
+
+    /// Whether every day reads its own input from this source.
+    /// A single file, stdin and inline inputs only hold the input of one day.
+    pub fn is_per_day(&self) -> bool {
+        match self {
+            InputSource::Folder(_) => true,
+            InputSource::Path(path) => path.is_dir(),
+            InputSource::Stdin | InputSource::Inline(_) => false,
+        }
+    }
+
+    /// Whether answers from `src/answers` apply to this input,
+    /// i.e. whether it is the default `src/inputs` folder.
+    pub fn has_answers(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<InputSource, pico_args::Error> {
+        let mut args = pico_args::Arguments::from_vec(args.iter().map(Into::into).collect());
+        InputSource::parse(&mut args)
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse(&[]).unwrap(), InputSource::default());
+        assert_eq!(parse(&["--input", "-"]).unwrap(), InputSource::Stdin);
+        assert_eq!(
+            parse(&["--input", "stress/15.txt"]).unwrap(),
+            InputSource::Path("stress/15.txt".into())
+        );
+        assert_eq!(
+            parse(&["--input-folder", "inputs-alt"]).unwrap(),
+            InputSource::Folder("inputs-alt".into())
+        );
+        assert!(parse(&["--input", "-", "--input-folder", "inputs-alt"]).is_err());
+    }
+
+    #[test]
+    fn test_read() {
+        let source = InputSource::Inline("1\n2\n".into());
+        assert_eq!(source.read(1).unwrap(), "1\n2\n");
+        assert!(!source.is_per_day());
+        assert!(!source.has_answers());
+
+        let source = InputSource::Folder("examples".into());
+        assert_eq!(
+            source.read(1).unwrap(),
+            try_read_file("examples", 1).unwrap()
+        );
+        assert!(source.is_per_day());
+
+        let source = InputSource::Path("src/examples".into());
+        assert_eq!(
+            source.read(2).unwrap(),
+            try_read_file("examples", 2).unwrap()
+        );
+        assert!(source.is_per_day());
+
+        let source = InputSource::Path("src/examples/03.txt".into());
+        assert_eq!(
+            source.read(1).unwrap(),
+            try_read_file("examples", 3).unwrap()
+        );
+        assert!(!source.is_per_day());
+
+        assert!(InputSource::default().has_answers());
+    }
+}
@@ -5,49 +5,181 @@
  */
 use std::env;
 use std::fs;
-
+use std::io;
+use workspace::Workspace;
+
+pub mod answers;
+pub mod aoc_client;
+pub mod calendar;
+pub mod config;
+pub mod days;
+pub mod download_cache;
+pub mod examples;
 pub mod helpers;
+pub mod input;
+pub mod parser;
+pub mod puzzle;
+pub mod runner;
+pub mod solution;
+pub mod submissions;
+pub mod workspace;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Runs (or with `--bench`, benchmarks) a solver against an input, compares its answer to
+/// `src/answers/NN.txt` unless the input was read from another source or the part is `manual`,
+/// prints it in the format selected via `--format` and returns the [`runner::PartResult`].
 #[macro_export]
 macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
+    ($day:expr, $part:expr, $solver:expr, $input:expr) => {
+        $crate::solve!($day, $part, $solver, $input, false)
+    };
+    ($day:expr, $part:expr, $solver:expr, $input:expr, $manual:expr) => {{
+        let options = $crate::runner::options();
+        let mut result = $crate::runner::run_part($day, $part, $solver, $input, options);
+        if options.input.has_answers() && !$manual {
+            result.verify(&$crate::answers::Answers::load($day));
+        }
+        $crate::runner::print_result(&result, options.format);
+        result
+    }};
+}
+
+/// Generates the `main` function of a day's binary from its [`solution::Solution`].
+/// Selects the solution's year, or the one given via `--year`, for the [`workspace`].
+/// Reads the input from the source selected via `--input` or `--input-folder`, solves
+/// both parts (or only part one, e.g. for day 25) in order, handles the benchmark baseline
+/// and exits with a non-zero status if an answer disagrees with the answers store.
+#[macro_export]
+macro_rules! main {
+    (@parts $solution:ty, $($part:ident),+) => {
+        fn main() {
+            use $crate::solution::Solution;
+
+            let day = <$solution>::DAY;
+            let options = $crate::runner::options();
+            $crate::workspace::select(<$solution>::YEAR.or(options.year));
+            let input = &match options.input.read(day) {
+                Ok(input) => input,
+                Err(e) => {
+                    eprintln!("Failed to read input for day {:02} from {}: {e}", day, options.input);
+                    std::process::exit(1);
                 }
-            }
+            };
+            let mut results = Vec::new();
+            let mut part = 0;
+            $(
+                part += 1;
+                let manual = <$solution>::MANUAL.contains(&part);
+                results.push($crate::solve!(day, part, <$solution>::$part, input, manual));
+            )+
+            $crate::runner::finish(&results, options);
         }
+    };
+    ($solution:ty, part_one) => {
+        $crate::main!(@parts $solution, part_one);
+    };
+    ($solution:ty) => {
+        $crate::main!(@parts $solution, part_one, part_two);
+    };
+}
 
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
-    }};
+/// Generates `test_part_one` and `test_part_two` of a [`solution::Solution`] from the
+/// expected answer of each part, to be used in a day's `tests` module:
+///
+/// - `part_one = 17` solves `src/examples/NN.txt` and expects `17`.
+/// - `part_one = examples` checks every example in `src/examples/NN/`, see [`examples`].
+/// - `part_one = ["D2FE28" => 6, "38006F45291200" => 9]` solves inline inputs.
+/// - `part_two = manual` prints the answer to the example, e.g. letters drawn by the solution,
+///   for a visual check with `cargo test -- --nocapture`. It only fails if the part fails.
+/// - `part_two = not_implemented` expects `SolveError::NotImplemented`, like a scaffolded part
+///   that is not solved yet.
+///
+/// ```ignore
+/// advent_of_code::aoc_tests!(Day13, part_one = 17, part_two = manual);
+/// ```
+#[macro_export]
+macro_rules! aoc_tests {
+    ($solution:ty, $($part:ident = $expected:tt),+ $(,)?) => {
+        $($crate::aoc_tests!(@part $solution, $part, $expected);)+
+    };
+    (@part $solution:ty, part_one, $expected:tt) => {
+        $crate::aoc_tests!(@test test_part_one, $solution, part_one, 1, $expected);
+    };
+    (@part $solution:ty, part_two, $expected:tt) => {
+        $crate::aoc_tests!(@test test_part_two, $solution, part_two, 2, $expected);
+    };
+    (@test $name:ident, $solution:ty, $part:ident, $number:literal, examples) => {
+        #[test]
+        fn $name() {
+            $crate::examples::assert_examples::<$solution>($number);
+        }
+    };
+    (@test $name:ident, $solution:ty, $part:ident, $number:literal, manual) => {
+        #[test]
+        fn $name() {
+            let input = $crate::examples::read_example::<$solution>();
+            match <$solution as $crate::solution::Solution>::$part(&input) {
+                Ok(answer) => println!("Check the answer to part {} by hand:\n{answer}", $number),
+                Err(error) => panic!("part {} failed: {error}", $number),
+            }
+        }
+    };
+    (@test $name:ident, $solution:ty, $part:ident, $number:literal, not_implemented) => {
+        #[test]
+        fn $name() {
+            let input = $crate::examples::read_example::<$solution>();
+            assert_eq!(
+                <$solution as $crate::solution::Solution>::$part(&input),
+                Err($crate::solution::SolveError::NotImplemented)
+            );
+        }
+    };
+    (@test $name:ident, $solution:ty, $part:ident, $number:literal, [$($input:expr => $answer:expr),+ $(,)?]) => {
+        #[test]
+        fn $name() {
+            $(
+                assert_eq!(
+                    <$solution as $crate::solution::Solution>::$part($input),
+                    Ok($answer.into()),
+                    "input: {:?}",
+                    $input
+                );
+            )+
+        }
+    };
+    (@test $name:ident, $solution:ty, $part:ident, $number:literal, $expected:expr) => {
+        #[test]
+        fn $name() {
+            let input = $crate::examples::read_example::<$solution>();
+            assert_eq!(
+                <$solution as $crate::solution::Solution>::$part(&input),
+                Ok($expected.into())
+            );
+        }
+    };
 }
 
 pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+    try_read_file(folder, day).expect("could not open input file")
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{day:02}.txt"));
+/// Reads `NN.txt` from `folder` of the selected or configured year, see [`workspace`].
+pub fn try_read_file(folder: &str, day: u8) -> io::Result<String> {
+    try_read_workspace_file(Workspace::current(), folder, day)
+}
+
+/// Like [`read_file`], but for the given year, e.g. in tests of year-scoped solutions.
+pub fn read_year_file(year: u16, folder: &str, day: u8) -> String {
+    try_read_workspace_file(Workspace::new(Some(year)), folder, day)
+        .expect("could not open input file")
+}
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+fn try_read_workspace_file(workspace: Workspace, folder: &str, day: u8) -> io::Result<String> {
+    let cwd = env::current_dir()?;
+    fs::read_to_string(cwd.join(workspace.file(folder, day, "txt")))
 }
 
 fn parse_time(val: &str, postfix: &str) -> f64 {
@@ -123,112 +255,3 @@ mod tests {
         );
     }
 }
-
-pub mod aoc_cli {
-    use std::{
-        fmt::Display,
-        fs::create_dir_all,
-        process::{Command, Output, Stdio},
-    };
-
-    pub enum AocCliError {
-        CommandNotFound,
-        CommandNotCallable,
-        BadExitStatus(Output),
-        IoError,
-    }
-
-    impl Display for AocCliError {
-        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-            match self {
-                AocCliError::CommandNotFound => write!(f, "aoc-cli is not present in environment."),
-                AocCliError::CommandNotCallable => write!(f, "aoc-cli could not be called."),
-                AocCliError::BadExitStatus(_) => {
-                    write!(f, "aoc-cli exited with a non-zero status.")
-                }
-                AocCliError::IoError => write!(f, "could not write output files to file system."),
-            }
-        }
-    }
-
-    pub fn check() -> Result<(), AocCliError> {
-        Command::new("aoc")
-            .arg("-V")
-            .output()
-            .map_err(|_| AocCliError::CommandNotFound)?;
-        Ok(())
-    }
-
-    pub fn read(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        // TODO: output local puzzle if present.
-        let args = build_args("read", &[], day, year);
-        call_aoc_cli(&args)
-    }
-
-    pub fn download(day: u8, year: Option<u16>) -> Result<Output, AocCliError> {
-        let input_path = get_input_path(day);
-
-        let puzzle_path = get_puzzle_path(day);
-        create_dir_all("src/puzzles").map_err(|_| AocCliError::IoError)?;
-
-        let args = build_args(
-            "download",
-            &[
-                "--overwrite".into(),
-                "--input-file".into(),
-                input_path.to_string(),
-                "--puzzle-file".into(),
-                puzzle_path.to_string(),
-            ],
-            day,
-            year,
-        );
-
-        let output = call_aoc_cli(&args)?;
-
-        if output.status.success() {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
-            Ok(output)
-        } else {
-            Err(AocCliError::BadExitStatus(output))
-        }
-    }
-
-    fn get_input_path(day: u8) -> String {
-        let day_padded = format!("{day:02}");
-        format!("src/inputs/{day_padded}.txt")
-    }
-
-    fn get_puzzle_path(day: u8) -> String {
-        let day_padded = format!("{day:02}");
-        format!("src/puzzles/{day_padded}.md")
-    }
-
-    fn build_args(command: &str, args: &[String], day: u8, year: Option<u16>) -> Vec<String> {
-        let mut cmd_args = args.to_vec();
-
-        if let Some(year) = year {
-            cmd_args.push("--year".into());
-            cmd_args.push(year.to_string());
-        }
-
-        cmd_args.append(&mut vec!["--day".into(), day.to_string(), command.into()]);
-
-        cmd_args
-    }
-
-    fn call_aoc_cli(args: &[String]) -> Result<Output, AocCliError> {
-        if cfg!(debug_assertions) {
-            println!("Calling >aoc with: {}", args.join(" "));
-        }
-
-        Command::new("aoc")
-            .args(args)
-            .stdout(Stdio::inherit())
-            .stderr(Stdio::inherit())
-            .output()
-            .map_err(|_| AocCliError::CommandNotCallable)
-    }
-}
@@ -2,44 +2,217 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
+use advent_of_code::answers::Answers;
+use advent_of_code::config::{ansi, config};
+use advent_of_code::days::{self, Day};
+use advent_of_code::runner::filter::Filter;
+use advent_of_code::runner::parallel::run_in_order;
+use advent_of_code::runner::{
+    finish, print_result, reject_unused, run_part_isolated, total_elapsed, Options, OutputFormat,
+    PartResult, Status,
+};
+use advent_of_code::workspace;
 use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use itertools::Itertools;
+use std::io;
+use std::process;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{day:02}");
+struct Args {
+    options: Options,
+    filter: Filter,
+    timeout: Option<Duration>,
+    jobs: usize,
+}
 
-            let mut args = vec!["run", "--bin", &day];
-            if cfg!(not(debug_assertions)) {
-                args.push("--release");
-            }
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    let parsed = Args {
+        options: Options::parse(&mut args)?,
+        filter: Filter::parse(&mut args)?,
+        timeout: match args.opt_value_from_str("--timeout")? {
+            Some(0) => None,
+            Some(seconds) => Some(Duration::from_secs(seconds)),
+            None => config().timeout,
+        },
+        jobs: args
+            .opt_value_from_str(["-j", "--jobs"])?
+            .unwrap_or(config().jobs),
+    };
 
-            let cmd = Command::new("cargo").args(&args).output().unwrap();
+    reject_unused(args)?;
+    Ok(parsed)
+}
 
-            println!("----------");
-            println!("{ANSI_BOLD}| Day {day} |{ANSI_RESET}");
-            println!("----------");
+/// The results of a single day, collected without printing so days can run concurrently.
+struct DayReport {
+    day: u8,
+    has_input: bool,
+    results: Vec<PartResult>,
+}
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+fn run_day(day: &Day, args: &Args) -> DayReport {
+    let parts = day
+        .parts()
+        .filter(|(part, _)| args.filter.includes_part(*part));
 
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
-                }
-            );
+    // only a missing file in the default input folder means the day is not solved yet.
+    let input: Arc<str> = match args.options.input.read(day.day) {
+        Ok(input) if !input.is_empty() => input.into(),
+        Err(e) if e.kind() != io::ErrorKind::NotFound || !args.options.input.has_answers() => {
+            return DayReport {
+                day: day.day,
+                has_input: true,
+                results: parts
+                    .map(|(part, _)| {
+                        PartResult::failed(day.day, part, Status::InputError(e.to_string()))
+                    })
+                    .collect(),
+            };
+        }
+        _ => {
+            return DayReport {
+                day: day.day,
+                has_input: false,
+                results: parts
+                    .map(|(part, _)| PartResult::unsolved(day.day, part))
+                    .collect(),
+            };
+        }
+    };
+
+    let answers = if args.options.input.has_answers() {
+        Answers::load(day.day)
+    } else {
+        Answers::default()
+    };
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    let results = parts
+        .map(|(part, solver)| {
+            let mut result = run_part_isolated(
+                day.day,
+                part,
+                solver,
+                input.clone(),
+                &args.options,
+                args.timeout,
+            );
+            if !day.is_manual(part) {
+                result.verify(&answers);
             }
+            result
         })
-        .sum();
+        .collect();
+
+    DayReport {
+        day: day.day,
+        has_input: true,
+        results,
+    }
+}
+
+fn print_day(report: &DayReport, format: OutputFormat) {
+    if format == OutputFormat::Text {
+        println!("----------");
+        println!(
+            "{}| Day {:02} |{}",
+            ansi(ANSI_BOLD),
+            report.day,
+            ansi(ANSI_RESET)
+        );
+        println!("----------");
+
+        if !report.has_input {
+            println!("Not solved.");
+            return;
+        }
+    }
+
+    for result in &report.results {
+        print_result(result, format);
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {e}");
+            process::exit(1);
+        }
+    };
+
+    let workspace = workspace::select(args.options.year);
+    let Some(registered) = days::registered(workspace.year) else {
+        eprintln!(
+            "No solutions of {} are registered in src/days/mod.rs.",
+            workspace.year.unwrap_or_default()
+        );
+        process::exit(1);
+    };
+
+    let days: Vec<&Day> = registered
+        .iter()
+        .filter(|day| args.filter.includes_day(day.day))
+        .collect();
+
+    if !args.options.input.is_per_day() && days.len() != 1 {
+        eprintln!(
+            "Reading input from {} requires selecting a single day with --days.",
+            args.options.input
+        );
+        process::exit(1);
+    }
+
+    let mut results: Vec<PartResult> = Vec::new();
+    let timer = Instant::now();
+
+    run_in_order(
+        &days,
+        args.jobs,
+        |day| run_day(day, &args),
+        |report| {
+            print_day(&report, args.options.format);
+            results.extend(report.results);
+        },
+    );
+
+    let wall_clock = timer.elapsed();
+
+    if args.options.format == OutputFormat::Text {
+        let total = total_elapsed(&results);
+
+        println!(
+            "{}Total:{} {}{:.2}ms (wall-clock: {:.2}ms){}",
+            ansi(ANSI_BOLD),
+            ansi(ANSI_RESET),
+            ansi(ANSI_ITALIC),
+            total.as_secs_f64() * 1000_f64,
+            wall_clock.as_secs_f64() * 1000_f64,
+            ansi(ANSI_RESET)
+        );
+    }
+
+    finish(&results, &args.options);
+    exit_on_input_errors(&results, &args.options);
+}
+
+/// Exits with a non-zero status if the input of any day could not be read.
+fn exit_on_input_errors(results: &[PartResult], options: &Options) {
+    let days: Vec<String> = results
+        .iter()
+        .filter(|result| matches!(result.status, Status::InputError(_)))
+        .map(|result| format!("day {:02}", result.day))
+        .dedup()
+        .collect();
 
-    println!("{ANSI_BOLD}Total:{ANSI_RESET} {ANSI_ITALIC}{total:.2}ms{ANSI_RESET}");
+    if !days.is_empty() {
+        eprintln!(
+            "Failed to read input for {} from {}.",
+            days.join(", "),
+            options.input
+        );
+        process::exit(1);
+    }
 }
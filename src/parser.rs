@@ -0,0 +1,250 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// A malformed part of an input. Lines and columns start at 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The offending text.
+    pub text: String,
+    pub message: String,
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}, found ",
+            self.line, self.column, self.message
+        )?;
+
+        if self.text.is_empty() {
+            write!(f, "nothing")
+        } else {
+            write!(f, "{:?}", self.text)
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A piece of the input that knows where it starts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span<'a> {
+    pub line: usize,
+    pub column: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Span<'a> {
+    /// The whole input, starting at line 1, column 1.
+    pub fn new(input: &'a str) -> Self {
+        Self {
+            line: 1,
+            column: 1,
+            text: input,
+        }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// An error pointing at this span.
+    pub fn error(&self, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line: self.line,
+            column: self.column,
+            text: self.text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// The span of `part`, which has to be a slice of this span's text.
+    fn sub(&self, part: &'a str) -> Span<'a> {
+        let offset = part.as_ptr() as usize - self.text.as_ptr() as usize;
+        let before = &self.text[..offset];
+
+        let (line, column) = match before.rfind('\n') {
+            Some(newline) => (
+                self.line + before.matches('\n').count(),
+                before[newline + 1..].chars().count() + 1,
+            ),
+            None => (self.line, self.column + before.chars().count()),
+        };
+
+        Span {
+            line,
+            column,
+            text: part,
+        }
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Span<'a>> {
+        let span = *self;
+        self.text.lines().map(move |line| span.sub(line))
+    }
+
+    /// Splits the span into blocks separated by an empty line.
+    pub fn blocks(&self) -> impl Iterator<Item = Span<'a>> {
+        let span = *self;
+        self.text.split("\n\n").map(move |block| span.sub(block))
+    }
+
+    pub fn split(&self, separator: &'a str) -> impl Iterator<Item = Span<'a>> {
+        let span = *self;
+        self.text.split(separator).map(move |part| span.sub(part))
+    }
+
+    pub fn split_whitespace(&self) -> impl Iterator<Item = Span<'a>> {
+        let span = *self;
+        self.text.split_whitespace().map(move |part| span.sub(part))
+    }
+
+    pub fn split_once(&self, separator: &str) -> Result<(Span<'a>, Span<'a>), ParseError> {
+        match self.text.split_once(separator) {
+            Some((lhs, rhs)) => Ok((self.sub(lhs), self.sub(rhs))),
+            None => Err(self.error(format!("expected {separator:?}"))),
+        }
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Span<'a>, ParseError> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.sub(rest)),
+            None => Err(self.error(format!("expected {prefix:?}"))),
+        }
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        self.sub(self.text.trim())
+    }
+
+    /// Every character of the span, with its position.
+    pub fn chars(&self) -> impl Iterator<Item = (Span<'a>, char)> {
+        let span = *self;
+        self.text
+            .char_indices()
+            .map(move |(offset, c)| (span.sub(&span.text[offset..offset + c.len_utf8()]), c))
+    }
+
+    pub fn parse<T>(&self) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.text
+            .parse()
+            .map_err(|e: T::Err| self.error(e.to_string()))
+    }
+}
+
+/// Splits an input into lines.
+pub fn lines(input: &str) -> impl Iterator<Item = Span<'_>> {
+    Span::new(input).lines()
+}
+
+/// Splits an input into blocks separated by an empty line.
+pub fn blocks(input: &str) -> impl Iterator<Item = Span<'_>> {
+    Span::new(input).blocks()
+}
+
+/// Reads a rectangular grid of characters. `cell` converts a character into a cell,
+/// characters it returns `None` for are reported as errors.
+pub fn grid<T>(
+    input: &str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut rows: Vec<Vec<T>> = vec![];
+
+    for line in lines(input) {
+        let row = line
+            .chars()
+            .map(|(span, c)| cell(c).ok_or_else(|| span.error("unexpected character")))
+            .collect::<Result<Vec<T>, ParseError>>()?;
+
+        if let Some(width) = rows.first().map(|row| row.len()) {
+            if row.len() != width {
+                return Err(line.error(format!("expected a row of {width} cells")));
+            }
+        }
+
+        rows.push(row);
+    }
+
+    Ok(rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positions() {
+        let input = "on x=1..2\n\nab cd\nef";
+        let blocks: Vec<Span> = blocks(input).collect();
+        assert_eq!(blocks.len(), 2);
+
+        let lines: Vec<Span> = blocks[1].lines().collect();
+        assert_eq!(
+            (lines[1].line, lines[1].column, lines[1].text),
+            (4, 1, "ef")
+        );
+
+        let words: Vec<Span> = lines[0].split_whitespace().collect();
+        assert_eq!(
+            (words[1].line, words[1].column, words[1].text),
+            (3, 4, "cd")
+        );
+
+        let (_, range) = blocks[0].split_once("=").unwrap();
+        let (start, end) = range.split_once("..").unwrap();
+        assert_eq!((start.column, end.column), (6, 9));
+        assert_eq!(end.parse::<u32>(), Ok(2));
+
+        let (span, c) = lines[1].chars().nth(1).unwrap();
+        assert_eq!((span.line, span.column, c), (4, 2, 'f'));
+    }
+
+    #[test]
+    fn test_errors() {
+        let line = lines("abc\nx=12a").nth(1).unwrap();
+        let (_, value) = line.split_once("=").unwrap();
+        let error = value.parse::<u32>().unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: invalid digit found in string, found \"12a\""
+        );
+        assert_eq!(
+            line.strip_prefix("y=").unwrap_err().to_string(),
+            "line 2, column 1: expected \"y=\", found \"x=12a\""
+        );
+        assert_eq!(
+            Span::new("").parse::<u32>().unwrap_err().to_string(),
+            "line 1, column 1: cannot parse integer from empty string, found nothing"
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        assert_eq!(
+            grid("12\n34\n", |c| c.to_digit(10)),
+            Ok(vec![vec![1, 2], vec![3, 4]])
+        );
+
+        let error = grid("12\n3x", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!((error.line, error.column, error.text.as_str()), (2, 2, "x"));
+
+        let error = grid("12\n345", |c| c.to_digit(10)).unwrap_err();
+        assert_eq!(error.message, "expected a row of 2 cells");
+    }
+}
@@ -0,0 +1,306 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+/// The heading that starts part two in a downloaded puzzle description.
+const PART_TWO_HEADING: &str = "## --- Part Two ---";
+
+/// The contents of the fenced code blocks in a puzzle description, in order.
+pub fn code_blocks(markdown: &str) -> Vec<String> {
+    let mut blocks = vec![];
+    let mut block: Option<String> = None;
+
+    for line in markdown.lines() {
+        match (&mut block, line.starts_with("```")) {
+            (None, true) => block = Some(String::new()),
+            (Some(_), true) => blocks.extend(block.take()),
+            (Some(block), false) => {
+                block.push_str(line);
+                block.push('\n');
+            }
+            (None, false) => {}
+        }
+    }
+
+    blocks
+}
+
+/// The example input of a puzzle, guessed to be its largest code block.
+pub fn example(markdown: &str) -> Option<String> {
+    code_blocks(markdown)
+        .into_iter()
+        .rev()
+        .max_by_key(|block| block.len())
+}
+
+/// The answers to the examples of both parts, guessed to be the last emphasized code
+/// (`*`42`*`) of each part. Part two is only known once part one is solved and the
+/// description is downloaded again.
+pub fn example_answers(markdown: &str) -> [Option<String>; 2] {
+    let (part_one, part_two) = match markdown.find(PART_TWO_HEADING) {
+        Some(start) => markdown.split_at(start),
+        None => (markdown, ""),
+    };
+
+    [last_answer(part_one), last_answer(part_two)]
+}
+
+fn last_answer(markdown: &str) -> Option<String> {
+    let mut answer = None;
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            continue;
+        }
+
+        let mut rest = line;
+        while let Some(start) = rest.find("*`") {
+            rest = &rest[start + 2..];
+            if let Some(end) = rest.find("`*") {
+                answer = Some(rest[..end].to_string());
+                rest = &rest[end + 2..];
+            }
+        }
+    }
+
+    answer
+}
+
+/// The part of a puzzle description, which is `1` or `2`. Part two is `None` until part one
+/// is solved and the description is downloaded again.
+pub fn part(markdown: &str, part: u8) -> Option<&str> {
+    let start = markdown.find(PART_TWO_HEADING);
+    match (part, start) {
+        (1, Some(start)) => Some(&markdown[..start]),
+        (1, None) => Some(markdown),
+        (2, Some(start)) => Some(&markdown[start..]),
+        _ => None,
+    }
+}
+
+/// Renders a puzzle description for the terminal: headings and emphasis in bold, code in
+/// italics, code blocks indented and paragraphs wrapped at `width` columns. `styled: false`
+/// leaves out the ANSI escape codes.
+pub fn render(markdown: &str, width: usize, styled: bool) -> String {
+    let escape = |code: &'static str| if styled { code } else { "" };
+    let mut rendered = String::new();
+    let mut paragraph: Vec<&str> = vec![];
+    let mut in_code_block = false;
+
+    for line in markdown.lines() {
+        let is_block = line.starts_with("```") || line.starts_with("## ") || line.starts_with("- ");
+        if !in_code_block && !is_block && !line.trim().is_empty() {
+            paragraph.push(line.trim());
+            continue;
+        }
+
+        if !paragraph.is_empty() {
+            rendered.push_str(&wrap(&paragraph.join(" "), "", width, styled));
+            paragraph.clear();
+        }
+
+        if line.starts_with("```") {
+            in_code_block = !in_code_block;
+        } else if in_code_block {
+            rendered.push_str(&format!(
+                "    {}{line}{}\n",
+                escape(ANSI_ITALIC),
+                escape(ANSI_RESET)
+            ));
+        } else if let Some(heading) = line.strip_prefix("## ") {
+            let heading = heading.replace(['*', '`'], "");
+            rendered.push_str(&format!(
+                "{}{heading}{}\n",
+                escape(ANSI_BOLD),
+                escape(ANSI_RESET)
+            ));
+        } else if let Some(item) = line.strip_prefix("- ") {
+            rendered.push_str("- ");
+            rendered.push_str(&wrap(item, "  ", width, styled)[2..]);
+        } else {
+            rendered.push('\n');
+        }
+    }
+
+    if !paragraph.is_empty() {
+        rendered.push_str(&wrap(&paragraph.join(" "), "", width, styled));
+    }
+
+    rendered.trim_end().to_string() + "\n"
+}
+
+/// Wraps a line of markdown at `width` columns, styling `*emphasis*` and `` `code` ``.
+/// Every line starts with `indent`.
+fn wrap(text: &str, indent: &str, width: usize, styled: bool) -> String {
+    let mut wrapped = indent.to_string();
+    let mut column = 0;
+    let (mut emphasis, mut code) = (false, false);
+
+    for word in strip_links(text).split(' ').filter(|word| !word.is_empty()) {
+        let length = word.chars().filter(|c| !matches!(c, '*' | '`')).count();
+        if column > 0 && indent.len() + column + 1 + length > width {
+            wrapped.push('\n');
+            wrapped.push_str(indent);
+            column = 0;
+        } else if column > 0 {
+            wrapped.push(' ');
+            column += 1;
+        }
+
+        let mut current = style(emphasis, code, styled);
+        wrapped.push_str(current);
+        for c in word.chars() {
+            match c {
+                '*' => emphasis = !emphasis,
+                '`' => code = !code,
+                c => {
+                    wrapped.push(c);
+                    continue;
+                }
+            }
+
+            let next = style(emphasis, code, styled);
+            if next != current {
+                if !current.is_empty() {
+                    wrapped.push_str(ANSI_RESET);
+                }
+                wrapped.push_str(next);
+                current = next;
+            }
+        }
+        if !current.is_empty() {
+            wrapped.push_str(ANSI_RESET);
+        }
+
+        column += length;
+    }
+
+    wrapped + "\n"
+}
+
+/// The escape code of the inline style, emphasis takes precedence over code.
+fn style(emphasis: bool, code: bool, styled: bool) -> &'static str {
+    match (styled, emphasis, code) {
+        (true, true, _) => ANSI_BOLD,
+        (true, false, true) => ANSI_ITALIC,
+        _ => "",
+    }
+}
+
+/// Replaces `[text](href)` links with their text.
+fn strip_links(text: &str) -> String {
+    let mut stripped = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('[') {
+        let link = rest[start..].find("](").and_then(|middle| {
+            let end = rest[start + middle..].find(')')?;
+            Some((middle, middle + end))
+        });
+        match link {
+            Some((middle, end)) => {
+                stripped.push_str(&rest[..start]);
+                stripped.push_str(&rest[start + 1..start + middle]);
+                rest = &rest[start + end + 1..];
+            }
+            None => {
+                stripped.push_str(&rest[..=start]);
+                rest = &rest[start + 1..];
+            }
+        }
+    }
+
+    stripped + rest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PUZZLE: &str = r#"## --- Day 1: Sonar Sweep ---
+
+For example, suppose you had the following report:
+
+```
+199
+200
+208
+```
+
+The first order of business is to figure out how quickly the depth increases, like *`200`*:
+
+```
+199 (N/A - no previous measurement)
+200 (*increased*)
+```
+
+In this example, there are *`7`* measurements that are larger than the previous measurement.
+
+## --- Part Two ---
+
+In this example, there are *`5`* sums that are larger than the previous sum, *not* `6`.
+"#;
+
+    #[test]
+    fn test_example() {
+        assert_eq!(
+            code_blocks(PUZZLE),
+            vec![
+                "199\n200\n208\n",
+                "199 (N/A - no previous measurement)\n200 (*increased*)\n"
+            ]
+        );
+        assert_eq!(
+            example(PUZZLE).as_deref(),
+            Some("199 (N/A - no previous measurement)\n200 (*increased*)\n")
+        );
+        assert_eq!(example("no code blocks"), None);
+    }
+
+    #[test]
+    fn test_example_answers() {
+        assert_eq!(
+            example_answers(PUZZLE),
+            [Some("7".to_string()), Some("5".to_string())]
+        );
+        assert_eq!(
+            example_answers(PUZZLE.split(PART_TWO_HEADING).next().unwrap()),
+            [Some("7".to_string()), None]
+        );
+        assert_eq!(example_answers(""), [None, None]);
+    }
+
+    #[test]
+    fn test_part() {
+        assert_eq!(
+            part(PUZZLE, 1).map(|part| part.ends_with("measurement.\n\n")),
+            Some(true)
+        );
+        assert_eq!(
+            part(PUZZLE, 2).map(|part| part.starts_with(PART_TWO_HEADING)),
+            Some(true)
+        );
+        assert_eq!(part("## --- Day 1 ---\n", 2), None);
+    }
+
+    #[test]
+    fn test_render() {
+        let markdown = "## --- Day 1: *Sonar* Sweep ---\n\nSee [this](/2021) and *`7`* or\n*not* `x`:\n\n```\n199\n  200\n```\n\n- a long list item that wraps\n";
+
+        assert_eq!(
+            render(markdown, 16, false),
+            "--- Day 1: Sonar Sweep ---\n\nSee this and 7\nor not x:\n\n    199\n      200\n\n- a long list\n  item that\n  wraps\n"
+        );
+        assert_eq!(
+            render("*`7`* and `a b`.", 80, true),
+            format!("{ANSI_BOLD}7{ANSI_RESET} and {ANSI_ITALIC}a{ANSI_RESET} {ANSI_ITALIC}b{ANSI_RESET}.\n")
+        );
+    }
+}
@@ -0,0 +1,320 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::PartResult;
+use crate::config::{ansi, config};
+use crate::days;
+use crate::workspace::Workspace;
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BaselineOptions {
+    /// Where the baseline is stored.
+    pub path: PathBuf,
+    /// Write the timings of this run to the baseline file.
+    pub save: bool,
+    /// Compare the timings of this run to the baseline file.
+    pub compare: bool,
+    /// Slowdown in percent above which a part is flagged as a regression.
+    pub threshold: f64,
+}
+
+/// The defaults of `[run] baseline` and `threshold` in `aoc.toml`.
+impl Default for BaselineOptions {
+    fn default() -> Self {
+        Self {
+            path: config().baseline.clone().into(),
+            save: false,
+            compare: false,
+            threshold: config().threshold,
+        }
+    }
+}
+
+impl BaselineOptions {
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        let default = Self::default();
+
+        Ok(Self {
+            save: args.contains("--save-baseline"),
+            compare: args.contains("--compare"),
+            path: args
+                .opt_value_from_str("--baseline")?
+                .unwrap_or(default.path),
+            threshold: args
+                .opt_value_from_str("--threshold")?
+                .unwrap_or(default.threshold),
+        })
+    }
+}
+
+/// Timings of solved parts, keyed by year, day and part.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Baseline {
+    timings: BTreeMap<(u16, u8, u8), Duration>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+}
+
+impl Comparison {
+    pub fn delta_nanos(&self) -> i128 {
+        self.current.as_nanos() as i128 - self.baseline.as_nanos() as i128
+    }
+
+    pub fn change_percent(&self) -> f64 {
+        if self.baseline.is_zero() {
+            return 0.0;
+        }
+
+        self.delta_nanos() as f64 / self.baseline.as_nanos() as f64 * 100.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change_percent() > threshold
+    }
+}
+
+impl Baseline {
+    /// Loads a baseline from `path`. A missing file yields an empty baseline.
+    pub fn load(path: &PathBuf) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Ok(Self::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &PathBuf) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    /// Parses `year,day,part,elapsed_ns` rows, skipping the header and malformed rows.
+    pub fn parse(contents: &str) -> Self {
+        let timings = contents
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split(',');
+                let year = fields.next()?.trim().parse().ok()?;
+                let day = fields.next()?.trim().parse().ok()?;
+                let part = fields.next()?.trim().parse().ok()?;
+                let nanos = fields.next()?.trim().parse().ok()?;
+                Some(((year, day, part), Duration::from_nanos(nanos)))
+            })
+            .collect();
+
+        Self { timings }
+    }
+
+    /// Adds or replaces the timings of all solved results of `year`.
+    pub fn merge<'a>(&mut self, year: u16, results: impl IntoIterator<Item = &'a PartResult>) {
+        for result in results.into_iter().filter(|result| result.is_solved()) {
+            self.timings
+                .insert((year, result.day, result.part), result.elapsed);
+        }
+    }
+
+    pub fn compare<'a>(
+        &self,
+        year: u16,
+        results: impl IntoIterator<Item = &'a PartResult>,
+    ) -> Vec<Comparison> {
+        results
+            .into_iter()
+            .filter(|result| result.is_solved())
+            .filter_map(|result| {
+                let baseline = self.timings.get(&(year, result.day, result.part))?;
+                Some(Comparison {
+                    day: result.day,
+                    part: result.part,
+                    baseline: *baseline,
+                    current: result.elapsed,
+                })
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "year,day,part,elapsed_ns")?;
+        for ((year, day, part), elapsed) in &self.timings {
+            writeln!(f, "{year},{day},{part},{}", elapsed.as_nanos())?;
+        }
+        Ok(())
+    }
+}
+
+fn format_delta(nanos: i128) -> String {
+    let sign = if nanos < 0 { "-" } else { "+" };
+    let duration = Duration::from_nanos(nanos.unsigned_abs() as u64);
+    format!("{sign}{duration:.2?}")
+}
+
+pub fn write_comparisons(
+    out: &mut impl Write,
+    comparisons: &[Comparison],
+    threshold: f64,
+) -> io::Result<()> {
+    writeln!(
+        out,
+        "{}Comparison with baseline:{}",
+        ansi(ANSI_BOLD),
+        ansi(ANSI_RESET)
+    )?;
+
+    if comparisons.is_empty() {
+        return writeln!(out, "No matching timings in baseline.");
+    }
+
+    for comparison in comparisons {
+        let flag = if comparison.is_regression(threshold) {
+            format!(" ⚠️  regression (> {threshold}%)")
+        } else {
+            "".to_string()
+        };
+
+        writeln!(
+            out,
+            "Day {:02} Part {}: {:.2?} -> {:.2?} {}({}, {:+.1}%){}{}",
+            comparison.day,
+            comparison.part,
+            comparison.baseline,
+            comparison.current,
+            ansi(ANSI_ITALIC),
+            format_delta(comparison.delta_nanos()),
+            comparison.change_percent(),
+            ansi(ANSI_RESET),
+            flag
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Compares `results` of the current year to the baseline and/or saves them, as selected by
+/// `options`. The comparison goes to stdout when `print` is set, i.e. for text output, and to
+/// stderr otherwise so JSON and CSV output stays parseable. Regressions never change the exit
+/// status.
+pub fn handle_baseline(results: &[PartResult], options: &BaselineOptions, print: bool) {
+    if !options.save && !options.compare {
+        return;
+    }
+
+    let year = Workspace::current().year.unwrap_or(days::YEAR);
+
+    let mut baseline = match Baseline::load(&options.path) {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("Failed to read baseline {:?}: {e}", options.path);
+            return;
+        }
+    };
+
+    if options.compare {
+        let comparisons = baseline.compare(year, results);
+        let _ = if print {
+            write_comparisons(&mut io::stdout().lock(), &comparisons, options.threshold)
+        } else {
+            write_comparisons(&mut io::stderr().lock(), &comparisons, options.threshold)
+        };
+    }
+
+    if options.save {
+        baseline.merge(year, results);
+        match baseline.save(&options.path) {
+            Ok(_) if print => println!("🎄 Saved baseline to {:?}.", options.path),
+            Ok(_) => eprintln!("🎄 Saved baseline to {:?}.", options.path),
+            Err(e) => eprintln!("Failed to write baseline {:?}: {e}", options.path),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::solve_part;
+
+    fn result(day: u8, part: u8, micros: u64) -> PartResult {
+        let mut result = solve_part(day, part, |_| Ok(0), "");
+        result.elapsed = Duration::from_micros(micros);
+        result
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut baseline = Baseline::default();
+        baseline.merge(2021, &[result(15, 1, 100), result(1, 2, 5)]);
+        baseline.merge(2016, &[result(1, 2, 7)]);
+
+        let contents = baseline.to_string();
+        assert_eq!(
+            contents,
+            "year,day,part,elapsed_ns\n2016,1,2,7000\n2021,1,2,5000\n2021,15,1,100000\n"
+        );
+        assert_eq!(Baseline::parse(&contents), baseline);
+    }
+
+    #[test]
+    fn test_merge_replaces() {
+        let mut baseline =
+            Baseline::parse("year,day,part,elapsed_ns\n2021,1,1,1000\n2021,2,1,1000\n");
+        baseline.merge(2021, &[result(1, 1, 3)]);
+        assert_eq!(
+            baseline.to_string(),
+            "year,day,part,elapsed_ns\n2021,1,1,3000\n2021,2,1,1000\n"
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Baseline::parse("2021,1,1,100000\n2021,1,2,100000\n2016,2,1,1000\n");
+        let comparisons = baseline.compare(
+            2021,
+            &[result(1, 1, 150), result(1, 2, 80), result(2, 1, 1)],
+        );
+
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(comparisons[0].delta_nanos(), 50_000);
+        assert!((comparisons[0].change_percent() - 50.0).abs() < 1e-9);
+        assert!(comparisons[0].is_regression(10.0));
+        assert!((comparisons[1].change_percent() + 20.0).abs() < 1e-9);
+        assert!(!comparisons[1].is_regression(10.0));
+    }
+
+    #[test]
+    fn test_write_comparisons() {
+        let baseline = Baseline::parse("2021,1,1,100000\n");
+        let comparisons = baseline.compare(2021, &[result(1, 1, 150)]);
+
+        let mut out = Vec::new();
+        write_comparisons(&mut out, &comparisons, 10.0).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("Day 01 Part 1: 100.00µs -> 150.00µs"));
+        assert!(out.contains("(+50.00µs, +50.0%)"));
+        assert!(out.ends_with("regression (> 10%)\n"));
+
+        let mut out = Vec::new();
+        write_comparisons(&mut out, &[], 10.0).unwrap();
+        assert!(String::from_utf8(out)
+            .unwrap()
+            .ends_with("No matching timings in baseline.\n"));
+    }
+
+    #[test]
+    fn test_format_delta() {
+        assert_eq!(format_delta(-1500), "-1.50µs");
+        assert_eq!(format_delta(2_000_000), "+2.00ms");
+    }
+}
@@ -0,0 +1,216 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use super::{outcome, PartResult};
+use crate::answers::Verdict;
+use crate::solution::SolveError;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+/// Upper bound for the number of measured runs when benchmarking against a time budget.
+const MAX_RUNS: u32 = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchConfig {
+    /// Runs that are executed before measuring, e.g. to warm up caches.
+    pub warmup: u32,
+    /// A fixed number of measured runs. Takes precedence over `budget`.
+    pub runs: Option<u32>,
+    /// Keep measuring until this much time was spent, at least once.
+    pub budget: Duration,
+}
+
+impl Default for BenchConfig {
+    fn default() -> Self {
+        Self {
+            warmup: 1,
+            runs: None,
+            budget: Duration::from_secs(1),
+        }
+    }
+}
+
+impl BenchConfig {
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Option<Self>, pico_args::Error> {
+        let bench = args.contains("--bench");
+        let warmup = args.opt_value_from_str("--warmup")?;
+        let runs = args.opt_value_from_str("--runs")?;
+        let budget = args.opt_value_from_str("--budget-ms")?;
+
+        if !bench {
+            if warmup.is_some() || runs.is_some() || budget.is_some() {
+                return Err(pico_args::Error::ArgumentParsingFailed {
+                    cause: "--warmup, --runs and --budget-ms require --bench".into(),
+                });
+            }
+
+            return Ok(None);
+        }
+
+        let default = Self::default();
+
+        Ok(Some(Self {
+            warmup: warmup.unwrap_or(default.warmup),
+            runs,
+            budget: budget.map(Duration::from_millis).unwrap_or(default.budget),
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchStats {
+    pub runs: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl BenchStats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / nanos.len() as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / nanos.len() as f64;
+
+        Some(Self {
+            runs: sorted.len() as u32,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+/// Runs `solver` repeatedly after a warm-up and records timing statistics.
+/// The result's `elapsed` is the median of all measured runs.
+pub fn bench_part<T: Display>(
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> Result<T, SolveError>,
+    input: &str,
+    config: &BenchConfig,
+) -> PartResult {
+    for _ in 0..config.warmup {
+        let _ = solver(input);
+    }
+
+    let mut samples = Vec::new();
+    let started = Instant::now();
+
+    let result = loop {
+        let timer = Instant::now();
+        let result = solver(input);
+        samples.push(timer.elapsed());
+
+        let runs = samples.len() as u32;
+        let done = match config.runs {
+            Some(target) => runs >= target,
+            None => started.elapsed() >= config.budget || runs >= MAX_RUNS,
+        };
+
+        if done || result.is_err() {
+            break result;
+        }
+    };
+
+    let stats = BenchStats::from_samples(&samples);
+    let (answer, status) = outcome(result);
+
+    PartResult {
+        day,
+        part,
+        answer,
+        status,
+        elapsed: stats.map(|stats| stats.median).unwrap_or_default(),
+        verdict: Verdict::Unknown,
+        bench: stats,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples: Vec<Duration> = [4, 1, 3, 2].map(Duration::from_micros).to_vec();
+        let stats = BenchStats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_nanos(2500));
+        assert_eq!(stats.mean, Duration::from_nanos(2500));
+        assert_eq!(stats.stddev, Duration::from_nanos(1118));
+
+        let samples: Vec<Duration> = [5, 1, 9].map(Duration::from_micros).to_vec();
+        let stats = BenchStats::from_samples(&samples).unwrap();
+        assert_eq!(stats.median, Duration::from_micros(5));
+
+        assert_eq!(BenchStats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_bench_part_runs() {
+        let config = BenchConfig {
+            warmup: 2,
+            runs: Some(5),
+            budget: Duration::ZERO,
+        };
+
+        let result = bench_part(1, 1, |input| Ok(input.len()), "abc", &config);
+        assert_eq!(result.answer.as_deref(), Some("3"));
+        assert_eq!(result.bench.map(|stats| stats.runs), Some(5));
+        assert_eq!(result.elapsed, result.bench.unwrap().median);
+    }
+
+    #[test]
+    fn test_bench_part_budget() {
+        let config = BenchConfig {
+            warmup: 0,
+            runs: None,
+            budget: Duration::ZERO,
+        };
+
+        let result = bench_part(1, 1, |input| Ok(input.len()), "abc", &config);
+        assert_eq!(result.bench.map(|stats| stats.runs), Some(1));
+    }
+
+    #[test]
+    fn test_parse_config() {
+        let mut args = pico_args::Arguments::from_vec(vec![]);
+        assert_eq!(BenchConfig::parse(&mut args).unwrap(), None);
+
+        let mut args = pico_args::Arguments::from_vec(vec!["--runs".into(), "3".into()]);
+        assert!(BenchConfig::parse(&mut args).is_err());
+
+        let mut args = pico_args::Arguments::from_vec(vec![
+            "--bench".into(),
+            "--budget-ms".into(),
+            "250".into(),
+        ]);
+        assert_eq!(
+            BenchConfig::parse(&mut args).unwrap(),
+            Some(BenchConfig {
+                warmup: 1,
+                runs: None,
+                budget: Duration::from_millis(250),
+            })
+        );
+    }
+}
@@ -0,0 +1,142 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+/// A set of days, written as a comma-separated list of days and ranges, e.g. `10-18,22`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DayList(BTreeSet<u8>);
+
+impl DayList {
+    pub fn contains(&self, day: u8) -> bool {
+        self.0.contains(&day)
+    }
+}
+
+impl FromStr for DayList {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_day = |val: &str| -> Result<u8, String> {
+            match val.trim().parse() {
+                Ok(day) if (1..=25).contains(&day) => Ok(day),
+                _ => Err(format!("\"{val}\" is not a day between 1 and 25")),
+            }
+        };
+
+        let mut days = BTreeSet::new();
+
+        for element in s.split(',').filter(|element| !element.trim().is_empty()) {
+            match element.split_once('-') {
+                Some((start, end)) => {
+                    let (start, end) = (parse_day(start)?, parse_day(end)?);
+                    if start > end {
+                        return Err(format!("range \"{element}\" is empty"));
+                    }
+                    days.extend(start..=end);
+                }
+                None => {
+                    days.insert(parse_day(element)?);
+                }
+            }
+        }
+
+        if days.is_empty() {
+            return Err("no days given".into());
+        }
+
+        Ok(Self(days))
+    }
+}
+
+/// Selects which days and parts the all-days runner executes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filter {
+    pub days: Option<DayList>,
+    pub skip: Option<DayList>,
+    pub part: Option<u8>,
+}
+
+impl Filter {
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(Self {
+            days: args.opt_value_from_str("--days")?,
+            skip: args.opt_value_from_str("--skip")?,
+            part: args.opt_value_from_fn("--part", |val| match val {
+                "1" => Ok(1),
+                "2" => Ok(2),
+                _ => Err(format!("\"{val}\" is not a part, expected 1 or 2")),
+            })?,
+        })
+    }
+
+    pub fn includes_day(&self, day: u8) -> bool {
+        let selected = self.days.as_ref().is_none_or(|days| days.contains(day));
+        let skipped = self.skip.as_ref().is_some_and(|skip| skip.contains(day));
+        selected && !skipped
+    }
+
+    pub fn includes_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|selected| selected == part)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn days(list: &[u8]) -> DayList {
+        DayList(list.iter().copied().collect())
+    }
+
+    #[test]
+    fn test_parse_day_list() {
+        assert_eq!("10-12,22".parse(), Ok(days(&[10, 11, 12, 22])));
+        assert_eq!(" 3 , 1,3".parse(), Ok(days(&[1, 3])));
+        assert_eq!("25-25".parse(), Ok(days(&[25])));
+
+        assert!("0".parse::<DayList>().is_err());
+        assert!("26".parse::<DayList>().is_err());
+        assert!("12-10".parse::<DayList>().is_err());
+        assert!("a-b".parse::<DayList>().is_err());
+        assert!("".parse::<DayList>().is_err());
+    }
+
+    #[test]
+    fn test_filter() {
+        let filter = Filter {
+            days: Some(days(&[10, 11, 12, 22, 23])),
+            skip: Some(days(&[23])),
+            part: Some(2),
+        };
+
+        assert!(filter.includes_day(10));
+        assert!(filter.includes_day(22));
+        assert!(!filter.includes_day(23));
+        assert!(!filter.includes_day(1));
+        assert!(filter.includes_part(2));
+        assert!(!filter.includes_part(1));
+
+        let filter = Filter::default();
+        assert!(filter.includes_day(23));
+        assert!(filter.includes_part(1));
+    }
+
+    #[test]
+    fn test_parse_filter() {
+        let mut args = pico_args::Arguments::from_vec(
+            ["--days", "10-18,22", "--part", "2", "--skip", "15"]
+                .map(Into::into)
+                .to_vec(),
+        );
+        let filter = Filter::parse(&mut args).unwrap();
+        assert!(filter.includes_day(18));
+        assert!(!filter.includes_day(15));
+        assert_eq!(filter.part, Some(2));
+
+        let mut args = pico_args::Arguments::from_vec(["--part", "3"].map(Into::into).to_vec());
+        assert!(Filter::parse(&mut args).is_err());
+    }
+}
@@ -0,0 +1,633 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::answers::{Answers, Verdict};
+use crate::calendar;
+use crate::config::{ansi, config};
+use crate::input::InputSource;
+use crate::solution::SolveError;
+use crate::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use baseline::{handle_baseline, BaselineOptions};
+use bench::{bench_part, BenchConfig, BenchStats};
+use std::any::Any;
+use std::fmt::{self, Display};
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::str::FromStr;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Once, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub mod baseline;
+pub mod bench;
+pub mod filter;
+pub mod parallel;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Solved,
+    Unsolved,
+    Failed(SolveError),
+    TimedOut(Duration),
+    Panicked(String),
+    /// The input exists, or was expected to, but could not be read.
+    InputError(String),
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Solved => write!(f, "solved"),
+            Status::Unsolved => write!(f, "unsolved"),
+            Status::Failed(error) => write!(f, "{error}"),
+            Status::TimedOut(limit) => write!(f, "timed out after {limit:.2?}"),
+            Status::Panicked(message) => write!(f, "panicked: {message}"),
+            Status::InputError(message) => write!(f, "failed to read input: {message}"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "csv" => Ok(OutputFormat::Csv),
+            _ => Err(format!(
+                "unknown output format \"{s}\", expected json, csv or text"
+            )),
+        }
+    }
+}
+
+/// Options shared by `cargo solve` and `cargo all`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Options {
+    pub format: OutputFormat,
+    pub bench: Option<BenchConfig>,
+    pub baseline: BaselineOptions,
+    pub input: InputSource,
+    /// The year selected via `--year`, see [`crate::workspace`].
+    pub year: Option<u16>,
+}
+
+impl Options {
+    pub fn parse(args: &mut pico_args::Arguments) -> Result<Self, pico_args::Error> {
+        Ok(Self {
+            format: args
+                .opt_value_from_str("--format")?
+                .unwrap_or(config().format),
+            bench: BenchConfig::parse(args)?,
+            baseline: BaselineOptions::parse(args)?,
+            input: InputSource::parse(args)?,
+            year: args.opt_value_from_fn(["-y", "--year"], calendar::parse_year)?,
+        })
+    }
+}
+
+/// The options selected for this process. Exits if the arguments are invalid.
+pub fn options() -> &'static Options {
+    static OPTIONS: OnceLock<Options> = OnceLock::new();
+
+    OPTIONS.get_or_init(|| {
+        let mut args = pico_args::Arguments::from_env();
+        let options = Options::parse(&mut args).and_then(|options| {
+            reject_unused(args)?;
+            Ok(options)
+        });
+        match options {
+            Ok(options) => options,
+            Err(e) => {
+                eprintln!("Failed to process arguments: {e}");
+                process::exit(1);
+            }
+        }
+    })
+}
+
+/// Fails if any arguments are left over after parsing, e.g. a misspelled flag.
+pub fn reject_unused(args: pico_args::Arguments) -> Result<(), pico_args::Error> {
+    match args.finish().first() {
+        Some(arg) => Err(pico_args::Error::Utf8ArgumentParsingFailed {
+            value: arg.to_string_lossy().into_owned(),
+            cause: "unknown argument".into(),
+        }),
+        None => Ok(()),
+    }
+}
+
+/// The outcome of running one part of a day against an input.
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub elapsed: Duration,
+    pub status: Status,
+    pub verdict: Verdict,
+    /// Timing statistics, only present when running with `--bench`.
+    pub bench: Option<BenchStats>,
+}
+
+impl PartResult {
+    /// A result for a part that was not run, e.g. because its input is missing.
+    pub fn unsolved(day: u8, part: u8) -> Self {
+        Self::failed(day, part, Status::Unsolved)
+    }
+
+    /// A result for a part that did not produce an answer.
+    pub fn failed(day: u8, part: u8, status: Status) -> Self {
+        Self {
+            day,
+            part,
+            answer: None,
+            elapsed: Duration::ZERO,
+            status,
+            verdict: Verdict::Unknown,
+            bench: None,
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        self.status == Status::Solved
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self.verdict, Verdict::Wrong { .. })
+    }
+
+    /// Compares the answer to the stored answer for this part.
+    pub fn verify(&mut self, answers: &Answers) {
+        self.verdict = answers.verify(self.part, self.answer.as_deref());
+    }
+}
+
+/// Splits the outcome of a solver into the rendered answer and the part's status.
+/// A part that is not implemented yet counts as unsolved.
+fn outcome<T: Display>(result: Result<T, SolveError>) -> (Option<String>, Status) {
+    match result {
+        Ok(answer) => (Some(answer.to_string()), Status::Solved),
+        Err(SolveError::NotImplemented) => (None, Status::Unsolved),
+        Err(error) => (None, Status::Failed(error)),
+    }
+}
+
+/// Runs `solver` against `input` once and records its answer and execution time.
+pub fn solve_part<T: Display>(
+    day: u8,
+    part: u8,
+    solver: impl FnOnce(&str) -> Result<T, SolveError>,
+    input: &str,
+) -> PartResult {
+    let timer = Instant::now();
+    let result = solver(input);
+    let elapsed = timer.elapsed();
+
+    let (answer, status) = outcome(result);
+
+    PartResult {
+        day,
+        part,
+        answer,
+        elapsed,
+        status,
+        verdict: Verdict::Unknown,
+        bench: None,
+    }
+}
+
+/// Runs `solver` once or, with `--bench`, benchmarks it.
+pub fn run_part<T: Display>(
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> Result<T, SolveError>,
+    input: &str,
+    options: &Options,
+) -> PartResult {
+    match &options.bench {
+        Some(config) => bench_part(day, part, solver, input, config),
+        None => solve_part(day, part, solver, input),
+    }
+}
+
+/// Runs a part on its own thread, so that a panic or exceeding `timeout` is reported
+/// as the part's status instead of taking down the caller.
+/// A timed out part keeps running in the background until the process exits.
+pub fn run_part_isolated<T: Display>(
+    day: u8,
+    part: u8,
+    solver: impl Fn(&str) -> Result<T, SolveError> + Send + 'static,
+    input: Arc<str>,
+    options: &Options,
+    timeout: Option<Duration>,
+) -> PartResult {
+    let (sender, receiver) = mpsc::channel();
+    let options = options.clone();
+
+    let spawned = thread::Builder::new()
+        .name(format!("day {day:02} part {part}"))
+        .spawn(move || {
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                run_part(day, part, solver, &input, &options)
+            }));
+            // the receiver is gone if the part timed out.
+            let _ = sender.send(result);
+        });
+
+    if let Err(e) = spawned {
+        return PartResult::failed(
+            day,
+            part,
+            Status::Panicked(format!("could not spawn solver thread: {e}")),
+        );
+    }
+
+    let received = match timeout {
+        Some(timeout) => receiver.recv_timeout(timeout),
+        None => receiver.recv().map_err(RecvTimeoutError::from),
+    };
+
+    match received {
+        Ok(Ok(result)) => result,
+        Ok(Err(payload)) => {
+            PartResult::failed(day, part, Status::Panicked(panic_message(&payload)))
+        }
+        Err(RecvTimeoutError::Timeout) => {
+            PartResult::failed(day, part, Status::TimedOut(timeout.unwrap_or_default()))
+        }
+        Err(RecvTimeoutError::Disconnected) => PartResult::failed(
+            day,
+            part,
+            Status::Panicked("solver thread exited unexpectedly".into()),
+        ),
+    }
+}
+
+fn panic_message(payload: &Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic payload".into()
+    }
+}
+
+pub fn print_result(result: &PartResult, format: OutputFormat) {
+    match format {
+        OutputFormat::Text => print_text(result),
+        OutputFormat::Json => println!("{}", to_json(result)),
+        OutputFormat::Csv => {
+            static HEADER: Once = Once::new();
+            HEADER.call_once(|| println!("{CSV_HEADER}"));
+            println!("{}", to_csv(result));
+        }
+    }
+}
+
+fn print_text(result: &PartResult) {
+    println!(
+        "🎄 {}Part {}{} 🎄",
+        ansi(ANSI_BOLD),
+        result.part,
+        ansi(ANSI_RESET)
+    );
+
+    let verdict = match &result.verdict {
+        Verdict::Correct => " ✅".to_string(),
+        Verdict::Wrong { expected } => format!(" ❌ expected {expected}"),
+        Verdict::Unknown => "".to_string(),
+    };
+
+    match (&result.answer, &result.status) {
+        (Some(answer), _) => {
+            println!(
+                "{} {}(elapsed: {:.2?}){}{}",
+                answer,
+                ansi(ANSI_ITALIC),
+                result.elapsed,
+                ansi(ANSI_RESET),
+                verdict
+            );
+        }
+        (None, Status::Solved | Status::Unsolved) => {
+            println!("not solved.{verdict}")
+        }
+        (None, status) => {
+            println!("{status}.{verdict}")
+        }
+    }
+
+    if let Some(stats) = &result.bench {
+        println!(
+            "{}min: {:.2?}, median: {:.2?}, mean: {:.2?}, stddev: {:.2?}, runs: {}{}",
+            ansi(ANSI_ITALIC),
+            stats.min,
+            stats.median,
+            stats.mean,
+            stats.stddev,
+            stats.runs,
+            ansi(ANSI_RESET)
+        );
+    }
+}
+
+const CSV_HEADER: &str =
+    "day,part,answer,elapsed_ns,status,verdict,runs,min_ns,median_ns,mean_ns,stddev_ns";
+
+/// Renders a result as a single-line JSON object.
+pub fn to_json(result: &PartResult) -> String {
+    let answer = match &result.answer {
+        Some(answer) => json_string(answer),
+        None => "null".into(),
+    };
+
+    let bench = match &result.bench {
+        Some(stats) => format!(
+            "{{\"runs\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"stddev_ns\":{}}}",
+            stats.runs,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.stddev.as_nanos()
+        ),
+        None => "null".into(),
+    };
+
+    format!(
+        "{{\"day\":{},\"part\":{},\"answer\":{},\"elapsed_ns\":{},\"status\":{},\"verdict\":\"{}\",\"bench\":{}}}",
+        result.day,
+        result.part,
+        answer,
+        result.elapsed.as_nanos(),
+        json_string(&result.status.to_string()),
+        result.verdict,
+        bench
+    )
+}
+
+/// Renders a result as a CSV row matching `CSV_HEADER`.
+pub fn to_csv(result: &PartResult) -> String {
+    let bench = match &result.bench {
+        Some(stats) => format!(
+            "{},{},{},{},{}",
+            stats.runs,
+            stats.min.as_nanos(),
+            stats.median.as_nanos(),
+            stats.mean.as_nanos(),
+            stats.stddev.as_nanos()
+        ),
+        None => ",,,,".into(),
+    };
+
+    format!(
+        "{},{},{},{},{},{},{}",
+        result.day,
+        result.part,
+        csv_field(result.answer.as_deref().unwrap_or_default()),
+        result.elapsed.as_nanos(),
+        csv_field(&result.status.to_string()),
+        result.verdict,
+        bench
+    )
+}
+
+fn json_string(val: &str) -> String {
+    let mut escaped = String::with_capacity(val.len() + 2);
+    escaped.push('"');
+
+    for c in val.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+fn csv_field(val: &str) -> String {
+    if val.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", val.replace('"', "\"\""))
+    } else {
+        val.to_string()
+    }
+}
+
+/// Compares to or saves the benchmark baseline and exits on wrong answers.
+pub fn finish(results: &[PartResult], options: &Options) {
+    handle_baseline(
+        results,
+        &options.baseline,
+        options.format == OutputFormat::Text,
+    );
+    exit_on_wrong_answers(results);
+}
+
+/// Exits with a non-zero status if any result disagrees with its stored answer.
+pub fn exit_on_wrong_answers<'a>(results: impl IntoIterator<Item = &'a PartResult>) {
+    let wrong: Vec<String> = results
+        .into_iter()
+        .filter(|result| result.is_wrong())
+        .map(|result| format!("day {:02} part {}", result.day, result.part))
+        .collect();
+
+    if !wrong.is_empty() {
+        eprintln!("Wrong answers for: {}", wrong.join(", "));
+        process::exit(1);
+    }
+}
+
+/// Sums the execution time of all solved parts.
+pub fn total_elapsed<'a>(results: impl IntoIterator<Item = &'a PartResult>) -> Duration {
+    results
+        .into_iter()
+        .filter(|result| result.is_solved())
+        .map(|result| result.elapsed)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Span;
+
+    #[test]
+    fn test_solve_part() {
+        let result = solve_part(1, 2, |input| Ok(input.len()), "12345");
+        assert_eq!(result.day, 1);
+        assert_eq!(result.part, 2);
+        assert_eq!(result.answer.as_deref(), Some("5"));
+        assert_eq!(result.status, Status::Solved);
+
+        let result = solve_part(1, 1, |_| Err::<u32, _>(SolveError::NotImplemented), "");
+        assert_eq!(result.answer, None);
+        assert_eq!(result.status, Status::Unsolved);
+
+        let error = SolveError::from(Span::new("x").error("expected a number"));
+        let result = solve_part(1, 1, |_| Err::<u32, _>(error.clone()), "");
+        assert_eq!(result.answer, None);
+        assert_eq!(result.status, Status::Failed(error));
+        assert_eq!(
+            result.status.to_string(),
+            "parse error at line 1, column 1: expected a number, found \"x\""
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse("5\n9");
+
+        let mut result = solve_part(1, 1, |input| Ok(input.len()), "12345");
+        result.verify(&answers);
+        assert_eq!(result.verdict, Verdict::Correct);
+        assert!(!result.is_wrong());
+
+        let mut result = solve_part(1, 2, |input| Ok(input.len()), "12345");
+        result.verify(&answers);
+        assert!(result.is_wrong());
+    }
+
+    #[test]
+    fn test_run_part_isolated() {
+        let options = Options::default();
+
+        let result = run_part_isolated(1, 1, |input| Ok(input.len()), "abc".into(), &options, None);
+        assert_eq!(result.answer.as_deref(), Some("3"));
+        assert_eq!(result.status, Status::Solved);
+
+        let result = run_part_isolated(
+            1,
+            2,
+            |input: &str| -> Result<u32, SolveError> { panic!("bad input: {input}") },
+            "abc".into(),
+            &options,
+            None,
+        );
+        assert_eq!(result.status, Status::Panicked("bad input: abc".into()));
+        assert!(!result.is_solved());
+
+        let timeout = Duration::from_millis(10);
+        let result = run_part_isolated(
+            1,
+            2,
+            |_| {
+                thread::sleep(Duration::from_secs(1));
+                Ok(1)
+            },
+            "".into(),
+            &options,
+            Some(timeout),
+        );
+        assert_eq!(result.status, Status::TimedOut(timeout));
+        assert_eq!(
+            to_csv(&result),
+            "1,2,,0,timed out after 10.00ms,unknown,,,,,"
+        );
+    }
+
+    #[test]
+    fn test_output_format_from_str() {
+        assert_eq!("json".parse(), Ok(OutputFormat::Json));
+        assert_eq!("csv".parse(), Ok(OutputFormat::Csv));
+        assert_eq!("text".parse(), Ok(OutputFormat::Text));
+        assert!("yaml".parse::<OutputFormat>().is_err());
+    }
+
+    #[test]
+    fn test_to_json() {
+        let mut result = PartResult {
+            day: 3,
+            part: 1,
+            answer: Some("a \"b\"\nc".into()),
+            elapsed: Duration::from_nanos(1500),
+            status: Status::Solved,
+            verdict: Verdict::Correct,
+            bench: None,
+        };
+        assert_eq!(
+            to_json(&result),
+            r#"{"day":3,"part":1,"answer":"a \"b\"\nc","elapsed_ns":1500,"status":"solved","verdict":"correct","bench":null}"#
+        );
+
+        result.answer = None;
+        result.status = Status::Unsolved;
+        result.verdict = Verdict::Unknown;
+        assert_eq!(
+            to_json(&result),
+            r#"{"day":3,"part":1,"answer":null,"elapsed_ns":1500,"status":"unsolved","verdict":"unknown","bench":null}"#
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        let mut result = PartResult {
+            day: 13,
+            part: 2,
+            answer: Some("42".into()),
+            elapsed: Duration::from_nanos(20),
+            status: Status::Solved,
+            verdict: Verdict::Wrong {
+                expected: "41".into(),
+            },
+            bench: None,
+        };
+        assert_eq!(to_csv(&result), "13,2,42,20,solved,wrong,,,,,");
+
+        result.answer = Some("a,\"b\"".into());
+        result.verdict = Verdict::Unknown;
+        assert_eq!(to_csv(&result), r#"13,2,"a,""b""",20,solved,unknown,,,,,"#);
+
+        result.bench = Some(BenchStats {
+            runs: 3,
+            min: Duration::from_nanos(10),
+            median: Duration::from_nanos(20),
+            mean: Duration::from_nanos(21),
+            stddev: Duration::from_nanos(2),
+        });
+        assert_eq!(
+            to_csv(&result),
+            r#"13,2,"a,""b""",20,solved,unknown,3,10,20,21,2"#
+        );
+    }
+
+    #[test]
+    fn test_total_elapsed() {
+        let result = |elapsed, status| PartResult {
+            day: 1,
+            part: 1,
+            answer: None,
+            elapsed: Duration::from_millis(elapsed),
+            status,
+            verdict: Verdict::Unknown,
+            bench: None,
+        };
+
+        assert_eq!(
+            total_elapsed(&[
+                result(3, Status::Solved),
+                result(5, Status::Unsolved),
+                result(7, Status::Solved),
+            ]),
+            Duration::from_millis(10)
+        );
+    }
+}
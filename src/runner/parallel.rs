@@ -0,0 +1,98 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+
+/// Runs `run` for every item on up to `jobs` threads and passes the results to `on_result`
+/// in the order of `items`, as soon as all preceding results are available.
+/// With a single job, items are run one after another on the calling thread.
+pub fn run_in_order<T, R>(
+    items: &[T],
+    jobs: usize,
+    run: impl Fn(&T) -> R + Sync,
+    mut on_result: impl FnMut(R),
+) where
+    T: Sync,
+    R: Send,
+{
+    if jobs <= 1 {
+        items.iter().map(&run).for_each(on_result);
+        return;
+    }
+
+    let next = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            let sender = sender.clone();
+            let (next, run) = (&next, &run);
+
+            scope.spawn(move || loop {
+                let index = next.fetch_add(1, Ordering::Relaxed);
+                let Some(item) = items.get(index) else {
+                    break;
+                };
+
+                if sender.send((index, run(item))).is_err() {
+                    break;
+                }
+            });
+        }
+
+        drop(sender);
+
+        let mut pending = BTreeMap::new();
+        let mut expected = 0;
+
+        for (index, result) in receiver {
+            pending.insert(index, result);
+
+            while let Some(result) = pending.remove(&expected) {
+                on_result(result);
+                expected += 1;
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_run_in_order() {
+        let items: Vec<u64> = (0..20).collect();
+
+        for jobs in [1, 4, 50] {
+            let mut results = Vec::new();
+            run_in_order(
+                &items,
+                jobs,
+                |item| {
+                    // finish later items first to exercise reordering.
+                    thread::sleep(Duration::from_millis(20 - item));
+                    item * 2
+                },
+                |result| results.push(result),
+            );
+
+            assert_eq!(
+                results,
+                items.iter().map(|item| item * 2).collect::<Vec<_>>()
+            );
+        }
+    }
+
+    #[test]
+    fn test_run_in_order_empty() {
+        let mut called = false;
+        run_in_order(&[] as &[u8], 4, |item| *item, |_| called = true);
+        assert!(!called);
+    }
+}
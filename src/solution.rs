@@ -0,0 +1,132 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::parser::ParseError;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// The answer to one part of a puzzle.
+/// Numbers of any integer type compare equal if their values are equal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{number}"),
+            Answer::Text(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+macro_rules! answer_from_number {
+    ($($type:ty),+) => {
+        $(
+            impl From<$type> for Answer {
+                fn from(number: $type) -> Self {
+                    Answer::Number(number as i128)
+                }
+            }
+        )+
+    };
+}
+
+answer_from_number!(u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// Why a part could not produce an answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The input is malformed.
+    Parse(ParseError),
+    /// The input is well-formed, but has no answer.
+    Unsolvable(String),
+    /// The part has not been solved yet.
+    NotImplemented,
+}
+
+impl SolveError {
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        SolveError::Unsolvable(message.into())
+    }
+}
+
+impl Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Parse(error) => write!(f, "parse error at {error}"),
+            SolveError::Unsolvable(message) => write!(f, "unsolvable input: {message}"),
+            SolveError::NotImplemented => write!(f, "not implemented"),
+        }
+    }
+}
+
+impl Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from(error: ParseError) -> Self {
+        SolveError::Parse(error)
+    }
+}
+
+/// The solution to a day's puzzle.
+pub trait Solution {
+    const DAY: u8;
+    /// The event year, set for solutions kept in a year-scoped layout, see [`crate::workspace`].
+    const YEAR: Option<u16> = None;
+    /// Parts whose answer is read by hand, e.g. letters drawn with `#`. Their answers are not
+    /// verified against the answers store and `cargo submit` refuses to submit them.
+    const MANUAL: &'static [u8] = &[];
+
+    fn part_one(input: &str) -> Result<Answer, SolveError>;
+
+    fn part_two(_input: &str) -> Result<Answer, SolveError> {
+        Err(SolveError::NotImplemented)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answer() {
+        assert_eq!(Answer::from(7_u32), Answer::from(7_usize));
+        assert_eq!(Answer::from(-3_isize).to_string(), "-3");
+        assert_eq!(Answer::from("ABC").to_string(), "ABC");
+        assert_ne!(Answer::from("7"), Answer::from(7));
+    }
+
+    #[test]
+    fn test_display_error() {
+        let error = ParseError {
+            line: 3,
+            column: 14,
+            text: "x".into(),
+            message: "unexpected character".into(),
+        };
+        assert_eq!(
+            SolveError::from(error).to_string(),
+            "parse error at line 3, column 14: unexpected character, found \"x\""
+        );
+        assert_eq!(
+            SolveError::unsolvable("no valid model number").to_string(),
+            "unsolvable input: no valid model number"
+        );
+    }
+}
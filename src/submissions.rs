@@ -0,0 +1,182 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::aoc_client::{Hint, SubmitOutcome};
+use crate::workspace::Workspace;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// A submission the website gave a final verdict on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: String,
+    /// `Ok` for a correct answer, otherwise the hint given for the incorrect one.
+    pub verdict: Result<(), Option<Hint>>,
+}
+
+/// Previous submissions for a day, stored in `src/submissions/NN.txt` as
+/// `part<TAB>verdict<TAB>answer` lines.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Submissions {
+    entries: Vec<Submission>,
+}
+
+impl Submissions {
+    pub fn path(day: u8) -> PathBuf {
+        Workspace::current().file("submissions", day, "txt")
+    }
+
+    /// Loads the history for `day`. A missing file yields an empty history.
+    pub fn load(day: u8) -> io::Result<Self> {
+        match fs::read_to_string(Self::path(day)) {
+            Ok(contents) => Ok(Self::parse(&contents)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, day: u8) -> io::Result<()> {
+        let path = Self::path(day);
+        if let Some(folder) = path.parent() {
+            fs::create_dir_all(folder)?;
+        }
+        fs::write(path, self.to_string())
+    }
+
+    /// Parses history lines, skipping malformed ones.
+    pub fn parse(contents: &str) -> Self {
+        let entries = contents
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.splitn(3, '\t');
+                let part = fields.next()?.parse().ok()?;
+                let verdict = match fields.next()? {
+                    "correct" => Ok(()),
+                    "too high" => Err(Some(Hint::TooHigh)),
+                    "too low" => Err(Some(Hint::TooLow)),
+                    "incorrect" => Err(None),
+                    _ => return None,
+                };
+                let answer = fields.next()?.to_string();
+                Some(Submission {
+                    part,
+                    answer,
+                    verdict,
+                })
+            })
+            .collect();
+
+        Self { entries }
+    }
+
+    /// Records the outcome of a submission. Outcomes without a verdict on the answer,
+    /// e.g. being rate-limited, are not recorded.
+    pub fn record(&mut self, part: u8, answer: &str, outcome: &SubmitOutcome) {
+        let verdict = match outcome {
+            SubmitOutcome::Correct => Ok(()),
+            SubmitOutcome::Incorrect(hint) => Err(*hint),
+            _ => return,
+        };
+
+        self.entries.push(Submission {
+            part,
+            answer: answer.to_string(),
+            verdict,
+        });
+    }
+
+    /// Why `answer` should not be submitted for `part`, if the history already rules it out:
+    /// the part is solved, the same answer was wrong, or it is beyond a too high/too low answer.
+    pub fn refusal(&self, part: u8, answer: &str) -> Option<String> {
+        let number = answer.parse::<i128>().ok();
+
+        self.entries
+            .iter()
+            .filter(|entry| entry.part == part)
+            .find_map(|entry| {
+                let previous = entry.answer.parse::<i128>().ok();
+                match (&entry.verdict, number.zip(previous)) {
+                    (Ok(()), _) => Some(format!(
+                        "part {part} was already solved with {}",
+                        entry.answer
+                    )),
+                    (Err(hint), _) if entry.answer == answer => Some(match hint {
+                        Some(hint) => format!("{answer} was already submitted and is {hint}"),
+                        None => format!("{answer} was already submitted and is incorrect"),
+                    }),
+                    (Err(Some(Hint::TooHigh)), Some((number, previous))) if number > previous => {
+                        Some(format!(
+                            "{answer} is higher than {previous}, which is too high"
+                        ))
+                    }
+                    (Err(Some(Hint::TooLow)), Some((number, previous))) if number < previous => {
+                        Some(format!(
+                            "{answer} is lower than {previous}, which is too low"
+                        ))
+                    }
+                    _ => None,
+                }
+            })
+    }
+}
+
+impl Display for Submissions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in &self.entries {
+            let verdict = match entry.verdict {
+                Ok(()) => "correct".to_string(),
+                Err(Some(hint)) => hint.to_string(),
+                Err(None) => "incorrect".to_string(),
+            };
+            writeln!(f, "{}\t{verdict}\t{}", entry.part, entry.answer)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut submissions = Submissions::default();
+        submissions.record(1, "150", &SubmitOutcome::Incorrect(Some(Hint::TooHigh)));
+        submissions.record(1, "abc", &SubmitOutcome::Incorrect(None));
+        submissions.record(1, "100", &SubmitOutcome::RateLimited(None));
+        submissions.record(1, "120", &SubmitOutcome::Correct);
+
+        assert_eq!(
+            submissions.to_string(),
+            "1\ttoo high\t150\n1\tincorrect\tabc\n1\tcorrect\t120\n"
+        );
+        assert_eq!(Submissions::parse(&submissions.to_string()), submissions);
+    }
+
+    #[test]
+    fn test_refusal() {
+        let submissions = Submissions::parse("1\ttoo high\t150\n1\ttoo low\t90\n2\tcorrect\t7\n");
+
+        assert_eq!(
+            submissions.refusal(1, "150"),
+            Some("150 was already submitted and is too high".into())
+        );
+        assert_eq!(
+            submissions.refusal(1, "200"),
+            Some("200 is higher than 150, which is too high".into())
+        );
+        assert_eq!(
+            submissions.refusal(1, "12"),
+            Some("12 is lower than 90, which is too low".into())
+        );
+        assert_eq!(submissions.refusal(1, "120"), None);
+        assert_eq!(
+            submissions.refusal(2, "8"),
+            Some("part 2 was already solved with 7".into())
+        );
+    }
+}
@@ -0,0 +1,143 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use crate::calendar;
+use crate::config::config;
+use crate::days;
+use std::env;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+/// Configures the year used when no `--year` is given.
+pub const YEAR_ENV: &str = "AOC_YEAR";
+
+/// Where the files of a year live.
+///
+/// The year of `DAYS` in `src/days/mod.rs` uses the `src/<folder>` layout and binaries named
+/// `NN`, which is what a repository with a single year uses. Every other year keeps its inputs,
+/// examples, answers and puzzles in `src/yYYYY` and its binaries are named `yYYYY_NN`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Workspace {
+    pub year: Option<u16>,
+    scoped: bool,
+}
+
+static SELECTED: OnceLock<Workspace> = OnceLock::new();
+
+/// Selects the year of this process, falling back to `$AOC_YEAR` if `year` is `None`.
+/// Only the first selection takes effect.
+pub fn select(year: Option<u16>) -> Workspace {
+    *SELECTED.get_or_init(|| Workspace::new(year.or_else(configured_year)))
+}
+
+/// The year configured via `$AOC_YEAR` if it is a valid year, or else in `aoc.toml`.
+pub fn configured_year() -> Option<u16> {
+    env::var(YEAR_ENV)
+        .ok()
+        .and_then(|year| calendar::parse_year(year.trim()).ok())
+        .or(config().year)
+}
+
+impl Workspace {
+    /// The workspace of `year`, using the year-scoped layout for any year but `days::YEAR`.
+    pub fn new(year: Option<u16>) -> Self {
+        let scoped = year.is_some_and(|year| year != days::YEAR);
+        Self { year, scoped }
+    }
+
+    /// The year-scoped workspace of `year`.
+    pub fn scoped(year: u16) -> Self {
+        Self {
+            year: Some(year),
+            scoped: true,
+        }
+    }
+
+    /// The workspace selected for this process, or the configured one if none was selected.
+    pub fn current() -> Self {
+        select(None)
+    }
+
+    /// The year whose files live in `src/yYYYY`, if any.
+    pub fn scoped_year(&self) -> Option<u16> {
+        self.year.filter(|_| self.scoped)
+    }
+
+    /// `src`, or `src/yYYYY` for a year-scoped workspace.
+    pub fn root(&self) -> PathBuf {
+        match self.scoped_year() {
+            Some(year) => PathBuf::from("src").join(format!("y{year}")),
+            None => PathBuf::from("src"),
+        }
+    }
+
+    /// The folder holding `folder`, e.g. `src/inputs` or `src/y2022/inputs`.
+    /// Folders can be renamed in `aoc.toml`.
+    pub fn dir(&self, folder: &str) -> PathBuf {
+        self.root().join(config().folder(folder))
+    }
+
+    /// The file of `day` in `folder`, e.g. `src/inputs/01.txt`.
+    pub fn file(&self, folder: &str, day: u8, extension: &str) -> PathBuf {
+        self.dir(folder).join(format!("{day:02}.{extension}"))
+    }
+
+    pub fn input_path(&self, day: u8) -> PathBuf {
+        self.file("inputs", day, "txt")
+    }
+
+    pub fn puzzle_path(&self, day: u8) -> PathBuf {
+        self.file("puzzles", day, "md")
+    }
+
+    /// The name of the binary of `day`, e.g. `01` or `y2022_01`.
+    pub fn bin_name(&self, day: u8) -> String {
+        match self.scoped_year() {
+            Some(year) => format!("y{year}_{day:02}"),
+            None => format!("{day:02}"),
+        }
+    }
+
+    pub fn bin_path(&self, day: u8) -> PathBuf {
+        PathBuf::from("src")
+            .join("bin")
+            .join(format!("{}.rs", self.bin_name(day)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flat_layout() {
+        for workspace in [Workspace::new(None), Workspace::new(Some(days::YEAR))] {
+            assert_eq!(workspace.scoped_year(), None);
+            assert_eq!(workspace.input_path(1), PathBuf::from("src/inputs/01.txt"));
+            assert_eq!(
+                workspace.puzzle_path(12),
+                PathBuf::from("src/puzzles/12.md")
+            );
+            assert_eq!(workspace.bin_path(3), PathBuf::from("src/bin/03.rs"));
+        }
+    }
+
+    #[test]
+    fn test_scoped_layout() {
+        assert_eq!(Workspace::new(Some(2016)).scoped_year(), Some(2016));
+
+        let workspace = Workspace::scoped(2022);
+        assert_eq!(workspace.scoped_year(), Some(2022));
+        assert_eq!(
+            workspace.input_path(1),
+            PathBuf::from("src/y2022/inputs/01.txt")
+        );
+        assert_eq!(
+            workspace.file("examples", 7, "txt"),
+            PathBuf::from("src/y2022/examples/07.txt")
+        );
+        assert_eq!(workspace.bin_name(3), "y2022_03");
+        assert_eq!(workspace.bin_path(3), PathBuf::from("src/bin/y2022_03.rs"));
+    }
+}